@@ -0,0 +1,95 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use complex::gcd::gcd_u32;
+use complex::Fraction;
+
+/// The trial division gcd that fractions were simplified with before the binary
+/// algorithm, kept here to compare against. 
+fn trial_division_gcd(a: u32, b: u32) -> u32
+{
+    let (mut small, mut large) = if a < b { (a, b) } else { (b, a) };
+
+    let mut i = 1;
+    let mut result = 1;
+
+    while i <= small
+    {
+        if small % i == 0 && large % i == 0
+        {
+            small /= i;
+            large /= i;
+            result *= i;
+            i = 1;
+        }
+
+        i += 1;
+    }
+
+    result
+}
+
+/// Runs `function` over every input, repeating until at least `minimum` has passed,
+/// and returns the average time taken per call. 
+fn measure<T: Copy>(inputs: &[T], minimum: Duration, mut function: impl FnMut(T)) -> Duration
+{
+    let start = Instant::now();
+    let mut calls = 0u32;
+
+    while start.elapsed() < minimum
+    {
+        for &input in inputs
+        {
+            function(black_box(input));
+        }
+
+        calls += inputs.len() as u32;
+    }
+
+    start.elapsed() / calls
+}
+
+fn compare(name: &str, inputs: &[(u32, u32)])
+{
+    let minimum = Duration::from_millis(500);
+
+    let binary = measure(inputs, minimum, |(a, b)| { black_box(gcd_u32(a, b)); });
+    let trial = measure(inputs, minimum, |(a, b)| { black_box(trial_division_gcd(a, b)); });
+
+    println!
+    (
+        "{name:<24} binary: {binary:>10.2?}   trial division: {trial:>10.2?}   speedup: {:.1}x",
+        trial.as_secs_f64() / binary.as_secs_f64()
+    );
+}
+
+fn main()
+{
+    // A fixed xorshift sequence so every run uses the same inputs.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    let mut next = move ||
+    {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let small: Vec<(u32, u32)> = (0..256).map(|_| ((next() % 1000) as u32 + 1, (next() % 1000) as u32 + 1)).collect();
+    let medium: Vec<(u32, u32)> = (0..64).map(|_| ((next() % 100_000) as u32 + 1, (next() % 100_000) as u32 + 1)).collect();
+    let large: Vec<(u32, u32)> = (0..16).map(|_| ((next() % 10_000_000) as u32 + 1, (next() % 10_000_000) as u32 + 1)).collect();
+
+    compare("gcd, values < 1e3", &small);
+    compare("gcd, values < 1e5", &medium);
+    compare("gcd, values < 1e7", &large);
+
+    let fractions: Vec<(i32, u32)> = (0..64).map(|_| ((next() % 2_000_000) as i32 - 1_000_000, (next() % 1_000_000) as u32 + 1)).collect();
+
+    let simplify = measure(&fractions, Duration::from_millis(500), |(numerator, denominator)|
+    {
+        black_box(Fraction::unchecked_from(numerator, denominator).simplify());
+    });
+
+    println!("{:<24} {simplify:>10.2?}", "simplify, values < 1e6");
+}
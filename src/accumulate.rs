@@ -0,0 +1,416 @@
+use std::borrow::Borrow;
+
+use crate::big_complex::BigComplex;
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::complex::GenericComplex;
+use crate::fraction::{signed_from_magnitude, ArithmeticError, GenericFraction};
+use crate::gcd::gcd;
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// A running sum of fractions over a common denominator, which is the least common
+/// multiple of the denominators seen so far. 
+/// The sum is only simplified when it stops fitting in an `i128` over a `u128`, and it
+/// moves to big integers if it still does not fit after that. 
+enum FractionSum
+{
+    Small(i128, u128),
+    Big(BigInt, BigUint),
+}
+
+impl FractionSum
+{
+    fn new() -> FractionSum
+    {
+        FractionSum::Small(0, 1)
+    }
+
+    /// Adds a fraction, returning `ArithmeticError::DivByZero` if its denominator is 0. 
+    fn add<T: SignedInteger>(&mut self, value: GenericFraction<T>) -> Result<(), ArithmeticError>
+    {
+        let (numerator, denominator): (i128, u128) = (value.get_numerator().into(), value.get_denominator().into());
+
+        if denominator == 0
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        if let FractionSum::Small(sum, common) = *self
+        {
+            let added = add_small((sum, common), (numerator, denominator)).or_else(||
+            {
+                let divisor = gcd(sum.unsigned_abs(), common);
+                let sum = signed_from_magnitude(sum < 0, sum.unsigned_abs() / divisor)?;
+
+                add_small((sum, common / divisor), (numerator, denominator))
+            });
+
+            match added
+            {
+                Some((sum, common)) => *self = FractionSum::Small(sum, common),
+                None => *self = FractionSum::Big(sum.into(), common.into()),
+            }
+        }
+
+        if let FractionSum::Big(sum, common) = self
+        {
+            let denominator = BigUint::from(denominator);
+            let divisor = common.gcd(&denominator);
+
+            let (sum_scale, scale) = (&denominator / &divisor, &*common / &divisor);
+
+            *sum = &*sum * &BigInt::from(sum_scale.clone()) + BigInt::from(numerator) * BigInt::from(scale);
+            *common = &*common * &sum_scale;
+        }
+
+        Ok(())
+    }
+
+    /// Simplifies the sum, returning `ArithmeticError::Overflow` if it does not fit in
+    /// `GenericFraction<T>`. 
+    fn finish<T: SignedInteger>(self) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        match self
+        {
+            FractionSum::Small(sum, common) =>
+            {
+                let divisor = gcd(sum.unsigned_abs(), common);
+
+                let numerator = signed_from_magnitude(sum < 0, sum.unsigned_abs() / divisor);
+                let denominator = T::Unsigned::try_from(common / divisor).ok();
+
+                numerator.zip(denominator)
+                    .map(|(numerator, denominator)| GenericFraction::unchecked_from(numerator, denominator))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+            FractionSum::Big(sum, common) =>
+            {
+                let sum = BigFraction::from(sum, common).expect("Fraction should not have 0 for denominator");
+
+                GenericFraction::try_from(sum).map_err(|_| ArithmeticError::Overflow)
+            }
+        }
+    }
+}
+
+/// Adds `numerator / denominator` to `sum / common`, returning `None` on overflow. 
+fn add_small((sum, common): (i128, u128), (numerator, denominator): (i128, u128)) -> Option<(i128, u128)>
+{
+    let divisor = gcd(common, denominator);
+
+    let (sum_scale, scale) = (denominator / divisor, common / divisor);
+
+    let sum = sum.checked_mul(i128::try_from(sum_scale).ok()?)?
+        .checked_add(numerator.checked_mul(i128::try_from(scale).ok()?)?)?;
+
+    Some((sum, common.checked_mul(sum_scale)?))
+}
+
+/// A running product, which is kept exactly once a partial product does not fit in the
+/// small type. 
+enum Product<S, B>
+{
+    Small(S),
+    Big(B),
+}
+
+/// Returns `ArithmeticError::DivByZero` if the fraction has 0 for its denominator. 
+fn check_denominator<T: SignedInteger>(value: GenericFraction<T>) -> Result<(), ArithmeticError>
+{
+    if value.get_denominator() == T::Unsigned::ZERO
+    {
+        return Err(ArithmeticError::DivByZero);
+    }
+
+    Ok(())
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Adds up fractions, returning `ArithmeticError::DivByZero` if any of them has 0 for
+    /// its denominator and `ArithmeticError::Overflow` if the simplified sum can not be
+    /// represented. 
+    /// The fractions are added over a common denominator and only simplified at the end,
+    /// so partial sums that do not fit in `GenericFraction<T>` are not an error. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let values = [Fraction::unchecked_from(1, 2), Fraction::unchecked_from(1, 3), Fraction::unchecked_from(1, 6)];
+    /// 
+    /// assert_eq!(Fraction::try_sum(values), Ok(Fraction::from_i32(1)));
+    /// assert_eq!(Fraction::try_sum(&[Fraction::from_i32(i32::MAX), Fraction::from_i32(1), Fraction::from_i32(-2)]), Ok(Fraction::from_i32(i32::MAX - 1)));
+    /// assert_eq!(Fraction::try_sum([Fraction::from_i32(i32::MAX); 2]), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn try_sum<I>(values: I) -> Result<GenericFraction<T>, ArithmeticError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<GenericFraction<T>>,
+    {
+        let mut sum = FractionSum::new();
+
+        for value in values
+        {
+            sum.add(*value.borrow())?;
+        }
+
+        sum.finish()
+    }
+
+    /// Multiplies fractions together, returning `ArithmeticError::DivByZero` if any of
+    /// them has 0 for its denominator and `ArithmeticError::Overflow` if the simplified
+    /// product can not be represented. 
+    /// Partial products that do not fit in `GenericFraction<T>` are kept exactly, so they
+    /// are not an error. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let values = [Fraction::from_i32(65536), Fraction::from_i32(65536), Fraction::unchecked_from(1, 4)];
+    /// 
+    /// assert_eq!(Fraction::try_product(values), Ok(Fraction::from_i32(1 << 30)));
+    /// assert_eq!(Fraction::try_product(&values[..2]), Err(ArithmeticError::Overflow));
+    /// assert_eq!(Fraction::try_product([Fraction::from_i32(0), Fraction::unchecked_from(1, 0)]), Err(ArithmeticError::DivByZero));
+    /// ```
+    pub fn try_product<I>(values: I) -> Result<GenericFraction<T>, ArithmeticError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<GenericFraction<T>>,
+    {
+        let mut product = Product::Small(GenericFraction::from_integer(T::ONE));
+
+        for value in values
+        {
+            let value = *value.borrow();
+
+            check_denominator(value)?;
+
+            product = match product
+            {
+                Product::Small(small) => match small.checked_mul(value)
+                {
+                    Ok(small) => Product::Small(small),
+                    Err(_) =>
+                    {
+                        let (small, value): (BigFraction, BigFraction) = (small.into(), value.into());
+
+                        Product::Big(small * value)
+                    }
+                },
+                Product::Big(big) =>
+                {
+                    let value: BigFraction = value.into();
+
+                    Product::Big(big * value)
+                }
+            };
+        }
+
+        match product
+        {
+            Product::Small(product) => Ok(product),
+            Product::Big(product) => GenericFraction::try_from(product).map_err(|_| ArithmeticError::Overflow),
+        }
+    }
+}
+
+impl<T: SignedInteger> std::iter::Sum for GenericFraction<T>
+{
+    /// Adds up fractions, panicking if the sum can not be represented. 
+    /// See `try_sum` for a version that returns an error instead. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let sum: Fraction = (1..=4).map(|n| Fraction::unchecked_from(1, n * (n + 1))).sum();
+    /// 
+    /// assert_eq!(sum, Fraction::unchecked_from(4, 5));
+    /// ```
+    fn sum<I: Iterator<Item = GenericFraction<T>>>(iter: I) -> Self
+    {
+        GenericFraction::try_sum(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<'a, T: SignedInteger> std::iter::Sum<&'a GenericFraction<T>> for GenericFraction<T>
+{
+    fn sum<I: Iterator<Item = &'a GenericFraction<T>>>(iter: I) -> Self
+    {
+        GenericFraction::try_sum(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::iter::Product for GenericFraction<T>
+{
+    /// Multiplies fractions together, panicking if the product can not be represented. 
+    /// See `try_product` for a version that returns an error instead. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let product: Fraction = (1..=5).map(|n| Fraction::unchecked_from(n, n as u32 + 1)).product();
+    /// 
+    /// assert_eq!(product, Fraction::unchecked_from(1, 6));
+    /// ```
+    fn product<I: Iterator<Item = GenericFraction<T>>>(iter: I) -> Self
+    {
+        GenericFraction::try_product(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<'a, T: SignedInteger> std::iter::Product<&'a GenericFraction<T>> for GenericFraction<T>
+{
+    fn product<I: Iterator<Item = &'a GenericFraction<T>>>(iter: I) -> Self
+    {
+        GenericFraction::try_product(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
+{
+    /// Adds up complex numbers, returning `ArithmeticError::DivByZero` if any component
+    /// has 0 for its denominator and `ArithmeticError::Overflow` if either component of
+    /// the simplified sum can not be represented. 
+    /// Each component is added up the same way as `GenericFraction::try_sum`. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let values = [Complex::from_i32_pair(1, 2), Complex::from_i32_pair(i32::MAX, -2), Complex::from_i32_pair(-3, 0)];
+    /// 
+    /// assert_eq!(Complex::try_sum(values), Ok(Complex::from_i32_pair(i32::MAX - 2, 0)));
+    /// assert_eq!(Complex::try_sum(&values[..2]), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn try_sum<I>(values: I) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<GenericComplex<GenericFraction<T>>>,
+    {
+        let (mut real, mut imaginary) = (FractionSum::new(), FractionSum::new());
+
+        for value in values
+        {
+            let (real_part, imaginary_part) = value.borrow().get_components();
+
+            real.add(real_part)?;
+            imaginary.add(imaginary_part)?;
+        }
+
+        Ok(GenericComplex::from(real.finish()?, imaginary.finish()?))
+    }
+
+    /// Multiplies complex numbers together, returning `ArithmeticError::DivByZero` if any
+    /// component has 0 for its denominator and `ArithmeticError::Overflow` if the
+    /// simplified product can not be represented. 
+    /// Partial products that do not fit in `GenericComplex<GenericFraction<T>>` are kept exactly, so they
+    /// are not an error. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let large = Complex::from_i32_pair(0, 65536);
+    /// let small = Complex::from_fraction(Fraction::unchecked_from(1, 65536));
+    /// 
+    /// assert_eq!(Complex::try_product([large, large, small, small]), Ok(Complex::from_i32_pair(-1, 0)));
+    /// assert_eq!(Complex::try_product([large, large, large]), Err(ArithmeticError::Overflow));
+    /// assert_eq!(Complex::try_product([Complex::from_i32_pair(1, 1); 8]), Ok(Complex::from_i32_pair(16, 0)));
+    /// ```
+    pub fn try_product<I>(values: I) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<GenericComplex<GenericFraction<T>>>,
+    {
+        let mut product = Product::Small(GenericComplex::ONE);
+
+        for value in values
+        {
+            let value = *value.borrow();
+            let (real, imaginary) = value.get_components();
+
+            check_denominator(real)?;
+            check_denominator(imaginary)?;
+
+            product = match product
+            {
+                Product::Small(small) => match small.checked_mul(value)
+                {
+                    Ok(small) => Product::Small(small),
+                    Err(_) =>
+                    {
+                        let (small, value): (BigComplex, BigComplex) = (small.into(), value.into());
+
+                        Product::Big(small * value)
+                    }
+                },
+                Product::Big(big) =>
+                {
+                    let value: BigComplex = value.into();
+
+                    Product::Big(big * value)
+                }
+            };
+        }
+
+        match product
+        {
+            Product::Small(product) => Ok(product),
+            Product::Big(product) => GenericComplex::try_from(product).map_err(|_| ArithmeticError::Overflow),
+        }
+    }
+}
+
+impl<T: SignedInteger> std::iter::Sum for GenericComplex<GenericFraction<T>>
+{
+    /// Adds up complex numbers, panicking if the sum can not be represented. 
+    /// See `try_sum` for a version that returns an error instead. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let values = [Complex::from_i32_pair(1, 2), Complex::from_i32_pair(3, -4)];
+    /// 
+    /// assert_eq!(values.iter().sum::<Complex>(), Complex::from_i32_pair(4, -2));
+    /// ```
+    fn sum<I: Iterator<Item = GenericComplex<GenericFraction<T>>>>(iter: I) -> Self
+    {
+        GenericComplex::try_sum(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<'a, T: SignedInteger> std::iter::Sum<&'a GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
+{
+    fn sum<I: Iterator<Item = &'a GenericComplex<GenericFraction<T>>>>(iter: I) -> Self
+    {
+        GenericComplex::try_sum(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::iter::Product for GenericComplex<GenericFraction<T>>
+{
+    /// Multiplies complex numbers together, panicking if the product can not be represented. 
+    /// See `try_product` for a version that returns an error instead. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let product: Complex = [Complex::from_i32_pair(0, 1); 3].into_iter().product();
+    /// 
+    /// assert_eq!(product, Complex::from_i32_pair(0, -1));
+    /// ```
+    fn product<I: Iterator<Item = GenericComplex<GenericFraction<T>>>>(iter: I) -> Self
+    {
+        GenericComplex::try_product(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<'a, T: SignedInteger> std::iter::Product<&'a GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
+{
+    fn product<I: Iterator<Item = &'a GenericComplex<GenericFraction<T>>>>(iter: I) -> Self
+    {
+        GenericComplex::try_product(iter).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
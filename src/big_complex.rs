@@ -0,0 +1,219 @@
+use crate::big_fraction::{BigFraction, OutOfRangeError};
+use crate::big_integer::{forward_binary_operator, BigInt};
+use crate::complex::{GenericComplex, ParseComplexError};
+use crate::fraction::GenericFraction;
+use crate::integer::SignedInteger;
+use crate::parse::parse_complex;
+
+/// A complex number with components stored as arbitrary precision `BigFraction`s. 
+pub type BigComplex = GenericComplex<BigFraction>;
+
+impl BigComplex
+{
+    /// Creates a complex number with the given fraction as its real component,
+    /// and 0 for its imaginary component. 
+    /// 
+    /// ```
+    /// use complex::{BigComplex, BigFraction};
+    /// 
+    /// let real: BigFraction = "1/2".parse().unwrap();
+    /// 
+    /// let complex = BigComplex::from_fraction(real.clone());
+    /// 
+    /// assert_eq!(*complex.get_real(), real);
+    /// ```
+    pub fn from_fraction(value: BigFraction) -> BigComplex
+    {
+        BigComplex::from(value, BigFraction::from_integer(BigInt::zero()))
+    }
+
+    /// Creates a complex number with the given fraction as its imaginary component,
+    /// and 0 for its real component. 
+    /// 
+    /// ```
+    /// use complex::{BigComplex, BigFraction};
+    /// 
+    /// let imaginary: BigFraction = "1/2".parse().unwrap();
+    /// 
+    /// let complex = BigComplex::from_fraction_imaginary(imaginary.clone());
+    /// 
+    /// assert_eq!(*complex.get_imaginary(), imaginary);
+    /// ```
+    pub fn from_fraction_imaginary(value: BigFraction) -> BigComplex
+    {
+        BigComplex::from(BigFraction::from_integer(BigInt::zero()), value)
+    }
+
+    /// Returns the real and imaginary components of the complex
+    /// number in a tuple. 
+    pub fn get_components(&self) -> (&BigFraction, &BigFraction)
+    {
+        (&self.real, &self.imaginary)
+    }
+
+    /// Returns the real component of the complex number. 
+    pub fn get_real(&self) -> &BigFraction
+    {
+        &self.real
+    }
+
+    /// Returns the imaginary component of the complex number. 
+    pub fn get_imaginary(&self) -> &BigFraction
+    {
+        &self.imaginary
+    }
+
+    /// Returns the complex conjugate of the number. 
+    /// 
+    /// ```
+    /// use complex::{BigComplex, Complex};
+    /// 
+    /// let complex: BigComplex = Complex::from_i32_pair(1, 5).into();
+    /// let conjugate: BigComplex = Complex::from_i32_pair(1, -5).into();
+    /// 
+    /// assert_eq!(complex.conjugate(), conjugate);
+    /// ```
+    pub fn conjugate(&self) -> BigComplex
+    {
+        BigComplex::from(self.real.clone(), -&self.imaginary)
+    }
+
+    /// Returns the absolute value of this complex number, squared. 
+    pub fn abs_squared(&self) -> BigFraction
+    {
+        &self.real * &self.real + &self.imaginary * &self.imaginary
+    }
+}
+
+impl std::fmt::Display for BigComplex
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if self.imaginary.get_numerator().is_zero()
+        {
+            return write!(f, "{}", self.real);
+        }
+
+        if self.real.get_numerator().is_zero()
+        {
+            return write!(f, "{}i", self.imaginary);
+        }
+
+        if !self.imaginary.get_numerator().is_negative()
+        {
+            write!(f, "{} + {}i", self.real, self.imaginary)
+        }
+        else
+        {
+            write!(f, "{} - {}i", self.real, self.imaginary.abs())
+        }
+    }
+}
+
+impl std::str::FromStr for BigComplex
+{
+    type Err = ParseComplexError;
+
+    /// Parses a complex number in any of the forms accepted by `GenericComplex`, with
+    /// exponents limited by `BigFraction::MAX_EXPONENT`. 
+    /// Polar forms whose components are irrational are approximated through `f64`,
+    /// giving the exact value of the nearest `f64` for each component. 
+    /// 
+    /// ```
+    /// use complex::BigComplex;
+    /// use complex::complex::ParseComplexError;
+    /// 
+    /// let value: BigComplex = "1e20 - 0.(3)i".parse().unwrap();
+    /// 
+    /// assert_eq!(value.to_string(), "100000000000000000000 - 1/3i");
+    /// assert!(matches!("1e4000000000i".parse::<BigComplex>(), Err(ParseComplexError::Overflow {..})));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        parse_complex(s, BigFraction::MAX_EXPONENT).map(|parsed| parsed.value)
+    }
+}
+
+impl std::ops::Add<&BigComplex> for &BigComplex
+{
+    type Output = BigComplex;
+
+    fn add(self, rhs: &BigComplex) -> Self::Output
+    {
+        BigComplex::from(&self.real + &rhs.real, &self.imaginary + &rhs.imaginary)
+    }
+}
+
+impl std::ops::Sub<&BigComplex> for &BigComplex
+{
+    type Output = BigComplex;
+
+    fn sub(self, rhs: &BigComplex) -> Self::Output
+    {
+        self + &(-rhs)
+    }
+}
+
+impl std::ops::Mul<&BigComplex> for &BigComplex
+{
+    type Output = BigComplex;
+
+    fn mul(self, rhs: &BigComplex) -> Self::Output
+    {
+        BigComplex::from
+        (
+            &self.real * &rhs.real - &self.imaginary * &rhs.imaginary,
+            &self.real * &rhs.imaginary + &self.imaginary * &rhs.real
+        )
+    }
+}
+
+impl std::ops::Div<&BigComplex> for &BigComplex
+{
+    type Output = BigComplex;
+
+    fn div(self, rhs: &BigComplex) -> Self::Output
+    {
+        let numerator = self * &rhs.conjugate();
+
+        // Product of rhs and rhs.conjugate()
+        let denominator = rhs.abs_squared();
+
+        BigComplex::from(numerator.real / &denominator, numerator.imaginary / &denominator)
+    }
+}
+
+forward_binary_operator!(BigComplex, Add, add, AddAssign, add_assign);
+forward_binary_operator!(BigComplex, Sub, sub, SubAssign, sub_assign);
+forward_binary_operator!(BigComplex, Mul, mul, MulAssign, mul_assign);
+forward_binary_operator!(BigComplex, Div, div, DivAssign, div_assign);
+
+impl<T: SignedInteger> From<GenericComplex<GenericFraction<T>>> for BigComplex
+{
+    fn from(value: GenericComplex<GenericFraction<T>>) -> Self
+    {
+        let (real, imaginary) = value.get_components();
+
+        BigComplex::from(real.into(), imaginary.into())
+    }
+}
+
+impl<T: SignedInteger> TryFrom<BigComplex> for GenericComplex<GenericFraction<T>>
+{
+    type Error = OutOfRangeError;
+
+    /// Converts a `BigComplex` back to a complex number, returning an `OutOfRangeError`
+    /// if either component does not fit in the target type. 
+    /// 
+    /// ```
+    /// use complex::{BigComplex, Complex};
+    /// 
+    /// let big: BigComplex = Complex::from_i32_pair(3, -4).into();
+    /// 
+    /// assert_eq!(Complex::try_from(big), Ok(Complex::from_i32_pair(3, -4)));
+    /// ```
+    fn try_from(value: BigComplex) -> Result<Self, Self::Error>
+    {
+        Ok(GenericComplex::from(GenericFraction::try_from(value.real)?, GenericFraction::try_from(value.imaginary)?))
+    }
+}
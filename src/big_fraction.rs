@@ -0,0 +1,398 @@
+use crate::big_integer::{forward_binary_operator, BigInt, BigUint};
+use crate::fraction::{DivByZeroError, GenericFraction, ParseFractionError};
+use crate::integer::SignedInteger;
+use crate::parse::parse_fraction;
+
+/// Represents a rational number through a fraction, storing the numerator as a `BigInt`
+/// and the denominator as a `BigUint`, so that arithmetic is exact no matter how large
+/// the components grow. 
+#[derive(Clone, Debug)]
+pub struct BigFraction
+{
+    numerator: BigInt,
+    denominator: BigUint,
+}
+
+/// The error returned when a big number does not fit in the requested primitive type. 
+#[derive(Debug, PartialEq, Eq)]
+pub struct OutOfRangeError;
+
+impl BigFraction
+{
+    /// The largest decimal exponent accepted when parsing a `BigFraction` or `BigComplex`,
+    /// beyond the number of characters in the input. 
+    /// Larger exponents return a `ParseFractionErrorKind::Overflow` error, since the power
+    /// of 10 takes time quadratic in the exponent to compute. 
+    /// 
+    /// ```
+    /// use complex::BigFraction;
+    /// use complex::fraction::ParseFractionErrorKind;
+    /// 
+    /// assert!("1e100000".parse::<BigFraction>().is_ok());
+    /// assert_eq!("1e4000000000".parse::<BigFraction>().unwrap_err().kind(), ParseFractionErrorKind::Overflow);
+    /// ```
+    pub const MAX_EXPONENT: u64 = 100_000;
+
+    /// Creates a fraction that is fully simplified. 
+    /// Will return `DivByZeroError` if denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt, BigUint};
+    /// 
+    /// let simplified = BigFraction::from(BigInt::from(2), BigUint::from(4u32)).unwrap();
+    /// 
+    /// assert_eq!(simplified.get_components(), (&BigInt::from(1), &BigUint::from(2u32)));
+    /// ```
+    pub fn from(numerator: BigInt, denominator: BigUint) -> Result<BigFraction, DivByZeroError>
+    {
+        Ok(BigFraction::unsimplified_from(numerator, denominator)?.simplify())
+    }
+
+    /// Creates a fraction that has no fractional simplification applied to it. 
+    /// 
+    /// Will return `DivByZeroError` if denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt, BigUint};
+    /// 
+    /// let unsimplified = BigFraction::unsimplified_from(BigInt::from(2), BigUint::from(4u32)).unwrap();
+    /// 
+    /// assert_eq!(unsimplified.get_components(), (&BigInt::from(2), &BigUint::from(4u32)));
+    /// ```
+    pub fn unsimplified_from(numerator: BigInt, denominator: BigUint) -> Result<BigFraction, DivByZeroError>
+    {
+        if denominator.is_zero()
+        {
+            return Err(DivByZeroError);
+        }
+
+        Ok(BigFraction {numerator, denominator})
+    }
+
+    /// Creates a fraction with `value` as the numerator and 1 as the denominator. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt};
+    /// 
+    /// let fraction = BigFraction::from_integer(BigInt::from(3));
+    /// 
+    /// assert_eq!(fraction.to_string(), "3");
+    /// ```
+    pub fn from_integer(value: BigInt) -> BigFraction
+    {
+        BigFraction {numerator: value, denominator: BigUint::one()}
+    }
+
+    /// Simplifies a fraction by dividing both the numerator and the denominator
+    /// by their greatest common factor. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt, BigUint};
+    /// 
+    /// let unsimplified = BigFraction::unsimplified_from(BigInt::from(-6), BigUint::from(8u32)).unwrap();
+    /// 
+    /// assert_eq!(unsimplified.simplify().to_string(), "-3/4");
+    /// ```
+    pub fn simplify(&self) -> BigFraction
+    {
+        let gcd = self.numerator.magnitude().gcd(&self.denominator);
+
+        BigFraction
+        {
+            numerator: BigInt::from_biguint(self.numerator.is_negative(), self.numerator.magnitude() / &gcd),
+            denominator: &self.denominator / &gcd,
+        }
+    }
+
+    /// Returns a tuple with the numerator for the first value, and the denominator
+    /// for the second. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt, BigUint};
+    /// 
+    /// let fraction: BigFraction = "1/2".parse().unwrap();
+    /// 
+    /// assert_eq!(fraction.get_components(), (&BigInt::from(1), &BigUint::from(2u32)));
+    /// ```
+    pub fn get_components(&self) -> (&BigInt, &BigUint)
+    {
+        (&self.numerator, &self.denominator)
+    }
+
+    /// Returns the numerator of this fraction. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt};
+    /// 
+    /// let fraction: BigFraction = "1/2".parse().unwrap();
+    /// 
+    /// assert_eq!(*fraction.get_numerator(), BigInt::from(1));
+    /// ```
+    pub fn get_numerator(&self) -> &BigInt
+    {
+        &self.numerator
+    }
+
+    /// Returns the denominator of this fraction. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigUint};
+    /// 
+    /// let fraction: BigFraction = "1/2".parse().unwrap();
+    /// 
+    /// assert_eq!(*fraction.get_denominator(), BigUint::from(2u32));
+    /// ```
+    pub fn get_denominator(&self) -> &BigUint
+    {
+        &self.denominator
+    }
+
+    /// Returns a number representing the sign of the fraction. 
+    /// - `0` if the number is 0
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    /// 
+    /// ```
+    /// use complex::{BigFraction, BigInt};
+    /// 
+    /// let fraction: BigFraction = "-5/2".parse().unwrap();
+    /// 
+    /// assert_eq!(fraction.signum(), BigInt::from(-1));
+    /// ```
+    pub fn signum(&self) -> BigInt
+    {
+        self.numerator.signum()
+    }
+
+    /// Returns a fraction with the numerator and denominator of `self` switched,
+    /// perserving the sign of the numerator, returning a `DivByZeroError` if the
+    /// denominator is zero. 
+    /// 
+    /// ```
+    /// use complex::BigFraction;
+    /// 
+    /// let fraction: BigFraction = "-1/2".parse().unwrap();
+    /// 
+    /// assert_eq!(fraction.reciprocal().unwrap().to_string(), "-2");
+    /// ```
+    pub fn reciprocal(&self) -> Result<BigFraction, DivByZeroError>
+    {
+        BigFraction::unsimplified_from
+        (
+            BigInt::from_biguint(self.numerator.is_negative(), self.denominator.clone()),
+            self.numerator.unsigned_abs()
+        )
+    }
+
+    /// Returns the absolute value of the fraction. 
+    /// 
+    /// ```
+    /// use complex::BigFraction;
+    /// 
+    /// let fraction: BigFraction = "-1/2".parse().unwrap();
+    /// 
+    /// assert_eq!(fraction.abs().to_string(), "1/2");
+    /// ```
+    pub fn abs(&self) -> BigFraction
+    {
+        BigFraction
+        {
+            numerator: BigInt::from_biguint(false, self.numerator.unsigned_abs()),
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for BigFraction
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if self.denominator != BigUint::one()
+        {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+        else
+        {
+            write!(f, "{}", self.numerator)
+        }
+    }
+}
+
+impl std::str::FromStr for BigFraction
+{
+    type Err = ParseFractionError;
+
+    /// Parses a fraction in any of the forms accepted by `GenericFraction`, with exponents
+    /// limited by `BigFraction::MAX_EXPONENT`. 
+    /// 
+    /// ```
+    /// use complex::BigFraction;
+    /// 
+    /// let fraction: BigFraction = "1.5e30".parse().unwrap();
+    /// 
+    /// assert_eq!(fraction.to_string(), "1500000000000000000000000000000");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        parse_fraction(s, BigFraction::MAX_EXPONENT)
+    }
+}
+
+impl PartialEq for BigFraction
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for BigFraction {}
+
+impl PartialOrd for BigFraction
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigFraction
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering
+    {
+        let lhs = &self.numerator * &BigInt::from(other.denominator.clone());
+        let rhs = &other.numerator * &BigInt::from(self.denominator.clone());
+
+        lhs.cmp(&rhs)
+    }
+}
+
+impl std::ops::Add<&BigFraction> for &BigFraction
+{
+    type Output = BigFraction;
+
+    fn add(self, rhs: &BigFraction) -> Self::Output
+    {
+        let denominator_gcd = self.denominator.gcd(&rhs.denominator);
+
+        let numerator = &self.numerator * &BigInt::from(&rhs.denominator / &denominator_gcd)
+            + &rhs.numerator * &BigInt::from(&self.denominator / &denominator_gcd);
+
+        let denominator = &self.denominator * &rhs.denominator / &denominator_gcd;
+
+        BigFraction::from(numerator, denominator).expect("Fraction should not have 0 for denominator")
+    }
+}
+
+impl std::ops::Neg for BigFraction
+{
+    type Output = BigFraction;
+
+    fn neg(self) -> Self::Output
+    {
+        BigFraction {numerator: -self.numerator, denominator: self.denominator}
+    }
+}
+
+impl std::ops::Neg for &BigFraction
+{
+    type Output = BigFraction;
+
+    fn neg(self) -> Self::Output
+    {
+        -self.clone()
+    }
+}
+
+impl std::ops::Sub<&BigFraction> for &BigFraction
+{
+    type Output = BigFraction;
+
+    fn sub(self, rhs: &BigFraction) -> Self::Output
+    {
+        self + &(-rhs)
+    }
+}
+
+impl std::ops::Mul<&BigFraction> for &BigFraction
+{
+    type Output = BigFraction;
+
+    fn mul(self, rhs: &BigFraction) -> Self::Output
+    {
+        BigFraction::from(&self.numerator * &rhs.numerator, &self.denominator * &rhs.denominator)
+            .expect("Fraction should not have 0 for denominator")
+    }
+}
+
+impl std::ops::Div<&BigFraction> for &BigFraction
+{
+    type Output = BigFraction;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: &BigFraction) -> Self::Output
+    {
+        self * &rhs.reciprocal().expect("Divide by 0")
+    }
+}
+
+forward_binary_operator!(BigFraction, Add, add, AddAssign, add_assign);
+forward_binary_operator!(BigFraction, Sub, sub, SubAssign, sub_assign);
+forward_binary_operator!(BigFraction, Mul, mul, MulAssign, mul_assign);
+forward_binary_operator!(BigFraction, Div, div, DivAssign, div_assign);
+
+impl<T: SignedInteger> From<GenericFraction<T>> for BigFraction
+{
+    /// Converts a fraction to a `BigFraction` without losing any precision. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, Fraction};
+    /// 
+    /// let fraction = Fraction::from(-3, 4).unwrap();
+    /// let big: BigFraction = fraction.into();
+    /// 
+    /// assert_eq!(big.to_string(), "-3/4");
+    /// ```
+    fn from(value: GenericFraction<T>) -> Self
+    {
+        let (numerator, denominator) = value.get_components();
+
+        BigFraction
+        {
+            numerator: BigInt::from(numerator.into()),
+            denominator: BigUint::from(denominator.into()),
+        }
+    }
+}
+
+impl<T: SignedInteger> TryFrom<BigFraction> for GenericFraction<T>
+{
+    type Error = OutOfRangeError;
+
+    /// Converts a `BigFraction` back to a fraction, returning an `OutOfRangeError` if
+    /// either component does not fit in the target type. 
+    /// 
+    /// ```
+    /// use complex::{BigFraction, Fraction, GenericFraction};
+    /// 
+    /// let big: BigFraction = "-3/4".parse().unwrap();
+    /// 
+    /// assert_eq!(Fraction::try_from(big.clone()), Ok(Fraction::from(-3, 4).unwrap()));
+    /// 
+    /// let large = big * "1000/1".parse::<BigFraction>().unwrap();
+    /// 
+    /// assert!(GenericFraction::<i8>::try_from(large).is_err());
+    /// ```
+    fn try_from(value: BigFraction) -> Result<Self, Self::Error>
+    {
+        let numerator = value.numerator.to_i128()
+            .and_then(|numerator| T::try_from(numerator).ok())
+            .ok_or(OutOfRangeError)?;
+
+        let denominator = value.denominator.to_u128()
+            .and_then(|denominator| T::Unsigned::try_from(denominator).ok())
+            .ok_or(OutOfRangeError)?;
+
+        Ok(GenericFraction::unchecked_from(numerator, denominator))
+    }
+}
@@ -0,0 +1,919 @@
+use std::cmp::Ordering;
+
+/// An arbitrary precision unsigned integer, stored as little endian `u32` digits. 
+/// Used as the denominator of a `BigFraction`. 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BigUint
+{
+    /// Never has trailing zero digits, so zero is represented by an empty `Vec`. 
+    digits: Vec<u32>,
+}
+
+/// An arbitrary precision signed integer. 
+/// Used as the numerator of a `BigFraction`. 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BigInt
+{
+    /// Never `true` when `magnitude` is zero. 
+    negative: bool,
+    magnitude: BigUint,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+const DIGIT_BITS: u32 = u32::BITS;
+
+impl BigUint
+{
+    /// Returns a `BigUint` representing 0. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// assert!(BigUint::zero().is_zero());
+    /// ```
+    pub const fn zero() -> BigUint
+    {
+        BigUint {digits: Vec::new()}
+    }
+
+    /// Returns a `BigUint` representing 1. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// assert_eq!(BigUint::one(), BigUint::from(1u32));
+    /// ```
+    pub fn one() -> BigUint
+    {
+        BigUint {digits: vec![1]}
+    }
+
+    /// Returns true if this number is 0. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// assert!(BigUint::from(0u32).is_zero());
+    /// assert!(!BigUint::from(3u32).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool
+    {
+        self.digits.is_empty()
+    }
+
+    /// Returns the value as a `u128`, or `None` if it does not fit. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// let value = BigUint::from(u128::MAX);
+    /// 
+    /// assert_eq!(value.to_u128(), Some(u128::MAX));
+    /// assert_eq!((value + BigUint::one()).to_u128(), None);
+    /// ```
+    pub fn to_u128(&self) -> Option<u128>
+    {
+        if self.digits.len() > 4
+        {
+            return None;
+        }
+
+        Some(self.digits.iter().rev().fold(0, |result, &digit| (result << DIGIT_BITS) | digit as u128))
+    }
+
+    /// Returns the lowest 128 bits of the value, which is the value wrapped around
+    /// at the bounds of a `u128`. 
+    pub(crate) fn low_u128(&self) -> u128
+    {
+        self.digits.iter().take(4).rev().fold(0, |result, &digit| (result << DIGIT_BITS) | digit as u128)
+    }
+
+    /// Computes the greatest common divisor of the two numbers with the euclidean algorithm. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// assert_eq!(BigUint::from(12u32).gcd(&BigUint::from(18u32)), BigUint::from(6u32));
+    /// ```
+    pub fn gcd(&self, other: &BigUint) -> BigUint
+    {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero()
+        {
+            let remainder = a.div_rem(&b).1;
+
+            a = b;
+            b = remainder;
+        }
+
+        a
+    }
+
+    /// Raises the number to the power of `exponent` by repeated squaring. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// assert_eq!(BigUint::from(10u32).pow(3), BigUint::from(1000u32));
+    /// assert_eq!(BigUint::from(7u32).pow(0), BigUint::one());
+    /// ```
+    pub fn pow(&self, mut exponent: u32) -> BigUint
+    {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+
+        while exponent > 0
+        {
+            if exponent & 1 == 1
+            {
+                result = &result * &base;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0
+            {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the quotient and remainder of dividing `self` by `divisor`. 
+    /// Panics if `divisor` is 0. 
+    /// 
+    /// ```
+    /// use complex::BigUint;
+    /// 
+    /// let (quotient, remainder) = BigUint::from(17u32).div_rem(&BigUint::from(5u32));
+    /// 
+    /// assert_eq!((quotient, remainder), (BigUint::from(3u32), BigUint::from(2u32)));
+    /// ```
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint)
+    {
+        if divisor.is_zero()
+        {
+            panic!("attempt to divide by zero");
+        }
+
+        if *self < *divisor
+        {
+            return (BigUint::zero(), self.clone());
+        }
+
+        if divisor.digits.len() == 1
+        {
+            let (quotient, remainder) = self.div_rem_digit(divisor.digits[0]);
+
+            return (quotient, BigUint::from(remainder));
+        }
+
+        self.div_rem_knuth(divisor)
+    }
+
+    /// Divides by a single digit, returning the quotient and remainder. 
+    fn div_rem_digit(&self, divisor: u32) -> (BigUint, u32)
+    {
+        let mut digits = vec![0; self.digits.len()];
+        let mut remainder = 0u64;
+
+        for (i, &digit) in self.digits.iter().enumerate().rev()
+        {
+            let current = (remainder << DIGIT_BITS) | digit as u64;
+
+            digits[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        (BigUint::from_digits(digits), remainder as u32)
+    }
+
+    /// Long division of numbers with at least two digits in the divisor, following
+    /// algorithm D from Knuth's The Art of Computer Programming, section 4.3.1. 
+    fn div_rem_knuth(&self, divisor: &BigUint) -> (BigUint, BigUint)
+    {
+        let shift = divisor.digits[divisor.digits.len() - 1].leading_zeros();
+
+        // Normalize so that the highest digit of the divisor has its top bit set,
+        // which keeps each estimated quotient digit at most 2 above the real one.
+        let v = shift_digits_left(&divisor.digits, shift);
+        let mut u = shift_digits_left(&self.digits, shift);
+
+        let n = divisor.digits.len();
+        let m = self.digits.len();
+
+        u.resize(m + 1, 0);
+
+        let mut quotient = vec![0u32; m - n + 1];
+
+        let base = 1u64 << DIGIT_BITS;
+        let mask = base - 1;
+
+        for j in (0..=m - n).rev()
+        {
+            let top = ((u[j + n] as u64) << DIGIT_BITS) | u[j + n - 1] as u64;
+
+            let mut estimate = top / v[n - 1] as u64;
+            let mut remainder = top % v[n - 1] as u64;
+
+            while estimate >= base
+                || estimate * v[n - 2] as u64 > ((remainder << DIGIT_BITS) | u[j + n - 2] as u64)
+            {
+                estimate -= 1;
+                remainder += v[n - 1] as u64;
+
+                if remainder >= base
+                {
+                    break;
+                }
+            }
+
+            let mut borrow = 0i64;
+
+            for i in 0..n
+            {
+                let product = estimate * v[i] as u64;
+                let difference = u[i + j] as i64 - borrow - (product & mask) as i64;
+
+                u[i + j] = difference as u32;
+                borrow = (product >> DIGIT_BITS) as i64 - (difference >> DIGIT_BITS);
+            }
+
+            let difference = u[j + n] as i64 - borrow;
+            u[j + n] = difference as u32;
+
+            quotient[j] = estimate as u32;
+
+            // The estimate was one too large, so add the divisor back.
+            if difference < 0
+            {
+                quotient[j] -= 1;
+
+                let mut carry = 0u64;
+
+                for i in 0..n
+                {
+                    let sum = u[i + j] as u64 + v[i] as u64 + carry;
+
+                    u[i + j] = sum as u32;
+                    carry = sum >> DIGIT_BITS;
+                }
+
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+        }
+
+        u.truncate(n);
+
+        let remainder = shift_digits_right(&u, shift);
+
+        (BigUint::from_digits(quotient), BigUint::from_digits(remainder))
+    }
+
+    /// Creates a number from little endian digits, removing any trailing zeroes. 
+    fn from_digits(mut digits: Vec<u32>) -> BigUint
+    {
+        while digits.last() == Some(&0)
+        {
+            digits.pop();
+        }
+
+        BigUint {digits}
+    }
+}
+
+/// Shifts the digits left by `shift` bits, where `shift` is less than 32,
+/// adding an extra digit for any overflow. 
+fn shift_digits_left(digits: &[u32], shift: u32) -> Vec<u32>
+{
+    if shift == 0
+    {
+        return digits.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(digits.len() + 1);
+    let mut carry = 0;
+
+    for &digit in digits
+    {
+        result.push((digit << shift) | carry);
+        carry = digit >> (DIGIT_BITS - shift);
+    }
+
+    if carry != 0
+    {
+        result.push(carry);
+    }
+
+    result
+}
+
+/// Shifts the digits right by `shift` bits, where `shift` is less than 32. 
+fn shift_digits_right(digits: &[u32], shift: u32) -> Vec<u32>
+{
+    if shift == 0
+    {
+        return digits.to_vec();
+    }
+
+    let mut result = vec![0; digits.len()];
+
+    for i in 0..digits.len()
+    {
+        let high = digits.get(i + 1).map_or(0, |&digit| digit << (DIGIT_BITS - shift));
+
+        result[i] = (digits[i] >> shift) | high;
+    }
+
+    result
+}
+
+impl PartialOrd for BigUint
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.digits.len().cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl std::fmt::Display for BigUint
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        const CHUNK: u32 = 1_000_000_000;
+
+        if self.is_zero()
+        {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut value = self.clone();
+
+        while !value.is_zero()
+        {
+            let (quotient, remainder) = value.div_rem_digit(CHUNK);
+
+            chunks.push(remainder);
+            value = quotient;
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        write!(f, "{}", chunks.next().expect("Value is not 0"))?;
+
+        for chunk in chunks
+        {
+            write!(f, "{chunk:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for BigUint
+{
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(ParseBigIntError);
+        }
+
+        let ten = BigUint::from(10u32);
+
+        Ok(digits.bytes().fold(BigUint::zero(), |result, byte| &result * &ten + BigUint::from((byte - b'0') as u32)))
+    }
+}
+
+impl std::ops::Add<&BigUint> for &BigUint
+{
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> Self::Output
+    {
+        let (short, long) = if self.digits.len() < rhs.digits.len() { (self, rhs) } else { (rhs, self) };
+
+        let mut digits = Vec::with_capacity(long.digits.len() + 1);
+        let mut carry = 0u64;
+
+        for (i, &digit) in long.digits.iter().enumerate()
+        {
+            let sum = digit as u64 + *short.digits.get(i).unwrap_or(&0) as u64 + carry;
+
+            digits.push(sum as u32);
+            carry = sum >> DIGIT_BITS;
+        }
+
+        if carry != 0
+        {
+            digits.push(carry as u32);
+        }
+
+        BigUint {digits}
+    }
+}
+
+impl std::ops::Sub<&BigUint> for &BigUint
+{
+    type Output = BigUint;
+
+    fn sub(self, rhs: &BigUint) -> Self::Output
+    {
+        if *self < *rhs
+        {
+            panic!("attempt to subtract with overflow");
+        }
+
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0i64;
+
+        for (i, &digit) in self.digits.iter().enumerate()
+        {
+            let difference = digit as i64 - *rhs.digits.get(i).unwrap_or(&0) as i64 - borrow;
+
+            digits.push(difference as u32);
+            borrow = (difference < 0) as i64;
+        }
+
+        BigUint::from_digits(digits)
+    }
+}
+
+impl std::ops::Mul<&BigUint> for &BigUint
+{
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> Self::Output
+    {
+        if self.is_zero() || rhs.is_zero()
+        {
+            return BigUint::zero();
+        }
+
+        let mut digits = vec![0u32; self.digits.len() + rhs.digits.len()];
+
+        for (i, &a) in self.digits.iter().enumerate()
+        {
+            let mut carry = 0u64;
+
+            for (j, &b) in rhs.digits.iter().enumerate()
+            {
+                let product = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+
+                digits[i + j] = product as u32;
+                carry = product >> DIGIT_BITS;
+            }
+
+            digits[i + rhs.digits.len()] = carry as u32;
+        }
+
+        BigUint::from_digits(digits)
+    }
+}
+
+impl std::ops::Div<&BigUint> for &BigUint
+{
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> Self::Output
+    {
+        self.div_rem(rhs).0
+    }
+}
+
+impl std::ops::Rem<&BigUint> for &BigUint
+{
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> Self::Output
+    {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Implements a binary operator and its assigning variant for the owned and borrowed
+/// forms of a type, given an existing implementation for `&$type op &$type`. 
+macro_rules! forward_binary_operator
+{
+    ($type:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) =>
+    {
+        impl std::ops::$trait<$type> for $type
+        {
+            type Output = $type;
+
+            fn $method(self, rhs: $type) -> Self::Output
+            {
+                std::ops::$trait::$method(&self, &rhs)
+            }
+        }
+
+        impl std::ops::$trait<&$type> for $type
+        {
+            type Output = $type;
+
+            fn $method(self, rhs: &$type) -> Self::Output
+            {
+                std::ops::$trait::$method(&self, rhs)
+            }
+        }
+
+        impl std::ops::$trait<$type> for &$type
+        {
+            type Output = $type;
+
+            fn $method(self, rhs: $type) -> Self::Output
+            {
+                std::ops::$trait::$method(self, &rhs)
+            }
+        }
+
+        impl std::ops::$assign_trait<$type> for $type
+        {
+            fn $assign_method(&mut self, rhs: $type)
+            {
+                *self = std::ops::$trait::$method(&*self, &rhs);
+            }
+        }
+
+        impl std::ops::$assign_trait<&$type> for $type
+        {
+            fn $assign_method(&mut self, rhs: &$type)
+            {
+                *self = std::ops::$trait::$method(&*self, rhs);
+            }
+        }
+    };
+}
+
+pub(crate) use forward_binary_operator;
+
+forward_binary_operator!(BigUint, Add, add, AddAssign, add_assign);
+forward_binary_operator!(BigUint, Sub, sub, SubAssign, sub_assign);
+forward_binary_operator!(BigUint, Mul, mul, MulAssign, mul_assign);
+forward_binary_operator!(BigUint, Div, div, DivAssign, div_assign);
+forward_binary_operator!(BigUint, Rem, rem, RemAssign, rem_assign);
+
+impl BigInt
+{
+    /// Returns a `BigInt` representing 0. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert!(BigInt::zero().is_zero());
+    /// ```
+    pub const fn zero() -> BigInt
+    {
+        BigInt {negative: false, magnitude: BigUint::zero()}
+    }
+
+    /// Returns a `BigInt` representing 1. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert_eq!(BigInt::one(), BigInt::from(1));
+    /// ```
+    pub fn one() -> BigInt
+    {
+        BigInt::from_biguint(false, BigUint::one())
+    }
+
+    /// Creates a signed integer from a sign and a magnitude. 
+    /// 
+    /// ```
+    /// use complex::{BigInt, BigUint};
+    /// 
+    /// assert_eq!(BigInt::from_biguint(true, BigUint::from(5u32)), BigInt::from(-5));
+    /// assert_eq!(BigInt::from_biguint(true, BigUint::zero()), BigInt::zero());
+    /// ```
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> BigInt
+    {
+        BigInt {negative: negative && !magnitude.is_zero(), magnitude}
+    }
+
+    /// Returns true if this number is 0. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert!(BigInt::from(0).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool
+    {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns true if this number is less than 0. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert!(BigInt::from(-3).is_negative());
+    /// assert!(!BigInt::from(0).is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool
+    {
+        self.negative
+    }
+
+    /// Returns a number representing the sign of this value. 
+    /// - `0` if the number is 0
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert_eq!(BigInt::from(-20).signum(), BigInt::from(-1));
+    /// ```
+    pub fn signum(&self) -> BigInt
+    {
+        if self.is_zero()
+        {
+            BigInt::zero()
+        }
+        else
+        {
+            BigInt::from_biguint(self.negative, BigUint::one())
+        }
+    }
+
+    /// Returns the absolute value of this number as a `BigUint`. 
+    /// 
+    /// ```
+    /// use complex::{BigInt, BigUint};
+    /// 
+    /// assert_eq!(BigInt::from(-20).unsigned_abs(), BigUint::from(20u32));
+    /// ```
+    pub fn unsigned_abs(&self) -> BigUint
+    {
+        self.magnitude.clone()
+    }
+
+    /// Returns a reference to the absolute value of this number. 
+    /// 
+    /// ```
+    /// use complex::{BigInt, BigUint};
+    /// 
+    /// assert_eq!(*BigInt::from(-20).magnitude(), BigUint::from(20u32));
+    /// ```
+    pub fn magnitude(&self) -> &BigUint
+    {
+        &self.magnitude
+    }
+
+    /// Returns the value as an `i128`, or `None` if it does not fit. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// 
+    /// assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+    /// assert_eq!((BigInt::from(i128::MIN) - BigInt::one()).to_i128(), None);
+    /// ```
+    pub fn to_i128(&self) -> Option<i128>
+    {
+        let magnitude = self.magnitude.to_u128()?;
+
+        if self.negative
+        {
+            0i128.checked_sub_unsigned(magnitude)
+        }
+        else
+        {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Returns the lowest 128 bits of the value in two's complement, which is the
+    /// value wrapped around at the bounds of an `i128`. 
+    pub(crate) fn low_i128(&self) -> i128
+    {
+        let low = self.magnitude.low_u128();
+
+        if self.negative
+        {
+            low.wrapping_neg() as i128
+        }
+        else
+        {
+            low as i128
+        }
+    }
+}
+
+impl PartialOrd for BigInt
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        match (self.negative, other.negative)
+        {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl std::fmt::Display for BigInt
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if self.negative
+        {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+impl std::str::FromStr for BigInt
+{
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        match s.strip_prefix('-')
+        {
+            Some(magnitude) if !magnitude.starts_with('+') => Ok(BigInt::from_biguint(true, magnitude.parse()?)),
+            Some(_) => Err(ParseBigIntError),
+            None => Ok(BigInt::from_biguint(false, s.parse()?)),
+        }
+    }
+}
+
+impl std::ops::Neg for BigInt
+{
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output
+    {
+        BigInt::from_biguint(!self.negative, self.magnitude)
+    }
+}
+
+impl std::ops::Neg for &BigInt
+{
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output
+    {
+        -self.clone()
+    }
+}
+
+impl std::ops::Add<&BigInt> for &BigInt
+{
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> Self::Output
+    {
+        if self.negative == rhs.negative
+        {
+            return BigInt::from_biguint(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+
+        match self.magnitude.cmp(&rhs.magnitude)
+        {
+            Ordering::Less => BigInt::from_biguint(rhs.negative, &rhs.magnitude - &self.magnitude),
+            _ => BigInt::from_biguint(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl std::ops::Sub<&BigInt> for &BigInt
+{
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output
+    {
+        self + &(-rhs)
+    }
+}
+
+impl std::ops::Mul<&BigInt> for &BigInt
+{
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output
+    {
+        BigInt::from_biguint(self.negative != rhs.negative, &self.magnitude * &rhs.magnitude)
+    }
+}
+
+impl std::ops::Div<&BigInt> for &BigInt
+{
+    type Output = BigInt;
+
+    /// Divides, rounding towards zero like the primitive integers. 
+    fn div(self, rhs: &BigInt) -> Self::Output
+    {
+        BigInt::from_biguint(self.negative != rhs.negative, &self.magnitude / &rhs.magnitude)
+    }
+}
+
+impl std::ops::Rem<&BigInt> for &BigInt
+{
+    type Output = BigInt;
+
+    /// Finds the remainder, which has the same sign as `self` like the primitive integers. 
+    fn rem(self, rhs: &BigInt) -> Self::Output
+    {
+        BigInt::from_biguint(self.negative, &self.magnitude % &rhs.magnitude)
+    }
+}
+
+forward_binary_operator!(BigInt, Add, add, AddAssign, add_assign);
+forward_binary_operator!(BigInt, Sub, sub, SubAssign, sub_assign);
+forward_binary_operator!(BigInt, Mul, mul, MulAssign, mul_assign);
+forward_binary_operator!(BigInt, Div, div, DivAssign, div_assign);
+forward_binary_operator!(BigInt, Rem, rem, RemAssign, rem_assign);
+
+macro_rules! impl_from_unsigned
+{
+    ($($unsigned:ty),*) =>
+    {
+        $(
+            impl From<$unsigned> for BigUint
+            {
+                fn from(value: $unsigned) -> Self
+                {
+                    let mut value = value as u128;
+                    let mut digits = Vec::new();
+
+                    while value != 0
+                    {
+                        digits.push(value as u32);
+                        value >>= DIGIT_BITS;
+                    }
+
+                    BigUint {digits}
+                }
+            }
+
+            impl From<$unsigned> for BigInt
+            {
+                fn from(value: $unsigned) -> Self
+                {
+                    BigInt::from_biguint(false, BigUint::from(value))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed
+{
+    ($($signed:ty),*) =>
+    {
+        $(
+            impl From<$signed> for BigInt
+            {
+                fn from(value: $signed) -> Self
+                {
+                    BigInt::from_biguint(value < 0, BigUint::from(value.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigUint> for BigInt
+{
+    fn from(value: BigUint) -> Self
+    {
+        BigInt::from_biguint(false, value)
+    }
+}
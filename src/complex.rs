@@ -1,15 +1,26 @@
-use crate::Fraction;
+use crate::big_complex::BigComplex;
+use crate::big_fraction::BigFraction;
+use crate::big_integer::BigInt;
+use crate::fraction::{ArithmeticError, Fraction, GenericFraction, ParseFractionError, ParseFractionErrorKind};
+use crate::integer::{SignedInteger, UnsignedInteger};
+use crate::parse::parse_complex;
+use crate::rounding::{round_big, RoundingMode};
 
-/// Represents a complex number through two `Fraction`s, one for the real
+/// Represents a complex number through two fractions of type `F`, one for the real
 /// component, and one for the imaginary component. 
+/// `F` is a `GenericFraction` for the fixed width complex numbers such as `Complex`,
+/// and a `BigFraction` for `BigComplex`. 
 #[derive(Clone, Copy, Debug)]
-pub struct Complex
+pub struct GenericComplex<F>
 {
-    real: Fraction,
-    imaginary: Fraction
+    pub(crate) real: F,
+    pub(crate) imaginary: F
 }
 
-impl Complex
+/// A complex number with components stored as `Fraction`s. 
+pub type Complex = GenericComplex<Fraction>;
+
+impl<F> GenericComplex<F>
 {
     /// Creates a complex number with the given fractional argumments for
     /// its real and imaginary components. 
@@ -24,10 +35,28 @@ impl Complex
     /// 
     /// assert_eq!(complex.get_components(), (real, imaginary));
     /// ```
-    pub const fn from(real: Fraction, imaginary: Fraction) -> Complex
+    pub const fn from(real: F, imaginary: F) -> GenericComplex<F>
     {
-        Complex {real, imaginary}
+        GenericComplex {real, imaginary}
     }
+}
+
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
+{
+    /// The complex number `0`. 
+    pub const ZERO: GenericComplex<GenericFraction<T>> = GenericComplex::from_integer_pair(T::ZERO, T::ZERO);
+
+    /// The complex number `1`. 
+    pub const ONE: GenericComplex<GenericFraction<T>> = GenericComplex::from_integer_pair(T::ONE, T::ZERO);
+
+    /// The imaginary unit `i`, the square root of -1. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+    /// ```
+    pub const I: GenericComplex<GenericFraction<T>> = GenericComplex::from_integer_pair(T::ZERO, T::ONE);
 
     /// Creates a complex number with the given fraction as its real component, 
     /// and 0 for its imaginary component. 
@@ -41,9 +70,9 @@ impl Complex
     /// 
     /// assert_eq!(complex.get_real(), real);
     /// ```
-    pub const fn from_fraction(value: Fraction) -> Complex
+    pub const fn from_fraction(value: GenericFraction<T>) -> GenericComplex<GenericFraction<T>>
     {
-        Complex::from(value, Fraction::from_i32(0))
+        GenericComplex::from(value, GenericFraction::from_integer(T::ZERO))
     }
 
     /// Creates a complex number with the given fraction as its imaginary component, 
@@ -58,65 +87,62 @@ impl Complex
     /// 
     /// assert_eq!(complex.get_imaginary(), imaginary);
     /// ```
-    pub const fn from_fraction_imaginary(value: Fraction) -> Complex
+    pub const fn from_fraction_imaginary(value: GenericFraction<T>) -> GenericComplex<GenericFraction<T>>
     {
-        Complex::from(Fraction::from_i32(0), value)
+        GenericComplex::from(GenericFraction::from_integer(T::ZERO), value)
     }
 
-    /// Creates a complex number with the given integer as its real component, 
-    /// and 0 for its imaginary component. 
+    /// Creates a complex number with the given integer argumments for
+    /// its real and imaginary components. 
     /// 
     /// ```
-    /// use complex::{Complex, Fraction};
-    /// 
-    /// let real = 10;
+    /// use complex::{GenericComplex, GenericFraction};
     /// 
-    /// let complex = Complex::from_i32(real);
+    /// let complex = GenericComplex::<GenericFraction<i64>>::from_integer_pair(10, 4);
     /// 
-    /// assert_eq!(complex.get_real(), Fraction::from_i32(real));
+    /// assert_eq!(complex.get_components(), (GenericFraction::from_integer(10), GenericFraction::from_integer(4)));
     /// ```
-    pub const fn from_i32(value: i32) -> Complex
+    pub const fn from_integer_pair(real: T, imaginary: T) -> GenericComplex<GenericFraction<T>>
     {
-        Complex::from_i32_pair(value, 0)
+        GenericComplex {real: GenericFraction::from_integer(real), imaginary: GenericFraction::from_integer(imaginary)}
     }
 
-    /// Creates a complex number with the given integer as its imaginary component, 
-    /// and 0 for its real component. 
+    /// Returns the real and imaginary components of the complex
+    /// number in a tuple. 
     /// 
     /// ```
     /// use complex::{Complex, Fraction};
     /// 
-    /// let imaginary = 10;
+    /// let real = Fraction::unchecked_from(1, 2);
+    /// let imaginary = Fraction::unchecked_from(3, 4);
     /// 
-    /// let complex = Complex::from_i32_imaginary(imaginary);
+    /// let complex = Complex::from(real, imaginary);
     /// 
-    /// assert_eq!(complex.get_imaginary(), Fraction::from_i32(imaginary));
+    /// assert_eq!(complex.get_components(), (real, imaginary));
     /// ```
-    pub const fn from_i32_imaginary(value: i32) -> Complex
+    pub const fn get_components(&self) -> (GenericFraction<T>, GenericFraction<T>)
     {
-        Complex::from_i32_pair(0, value)
+        (self.real, self.imaginary)
     }
 
-    /// Creates a complex number with the given integer argumments for
-    /// its real and imaginary components. 
+    /// Returns the real component of the complex number. 
     /// 
     /// ```
     /// use complex::{Complex, Fraction};
     /// 
-    /// let real = 10;
-    /// let imaginary = 4;
+    /// let real = Fraction::unchecked_from(1, 2);
+    /// let imaginary = Fraction::unchecked_from(3, 4);
     /// 
-    /// let complex = Complex::from_i32_pair(real, imaginary);
+    /// let complex = Complex::from(real, imaginary);
     /// 
-    /// assert_eq!(complex.get_components(), (Fraction::from_i32(real), Fraction::from_i32(imaginary)));
+    /// assert_eq!(complex.get_real(), real);
     /// ```
-    pub const fn from_i32_pair(real: i32, imaginary: i32) -> Complex
+    pub const fn get_real(&self) -> GenericFraction<T>
     {
-        Complex {real: Fraction::from_i32(real), imaginary: Fraction::from_i32(imaginary)}
+        self.real
     }
 
-    /// Returns the real and imaginary components of the complex
-    /// number in a tuple. 
+    /// Returns the imaginary component of the complex number. 
     /// 
     /// ```
     /// use complex::{Complex, Fraction};
@@ -126,74 +152,231 @@ impl Complex
     /// 
     /// let complex = Complex::from(real, imaginary);
     /// 
-    /// assert_eq!(complex.get_components(), (real, imaginary));
+    /// assert_eq!(complex.get_imaginary(), imaginary);
     /// ```
-    pub const fn get_components(&self) -> (Fraction, Fraction)
+    pub const fn get_imaginary(&self) -> GenericFraction<T>
     {
-        (self.real, self.imaginary)
+        self.imaginary
     }
 
-    /// Returns the real component of the complex number. 
+    /// Returns whether both components are in their canonical form, which is simplified
+    /// with a denominator that is not 0. 
     /// 
     /// ```
     /// use complex::{Complex, Fraction};
     /// 
-    /// let real = Fraction::unchecked_from(1, 2);
-    /// let imaginary = Fraction::unchecked_from(3, 4);
+    /// assert!(Complex::from_i32_pair(3, -4).is_canonical());
+    /// assert!(!Complex::from(Fraction::from_i32(3), Fraction::unchecked_from(-8, 2)).is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool
+    {
+        self.real.is_canonical() && self.imaginary.is_canonical()
+    }
+}
+
+impl Complex
+{
+    /// Creates a complex number with the given integer as its real component, 
+    /// and 0 for its imaginary component. 
     /// 
-    /// let complex = Complex::from(real, imaginary);
+    /// ```
+    /// use complex::{Complex, Fraction};
     /// 
-    /// assert_eq!(complex.get_real(), real);
+    /// let real = 10;
+    /// 
+    /// let complex = Complex::from_i32(real);
+    /// 
+    /// assert_eq!(complex.get_real(), Fraction::from_i32(real));
     /// ```
-    pub const fn get_real(&self) -> Fraction
+    pub const fn from_i32(value: i32) -> Complex
     {
-        self.real
+        Complex::from_i32_pair(value, 0)
     }
 
-    /// Returns the imaginary component of the complex number. 
+    /// Creates a complex number with the given integer as its imaginary component, 
+    /// and 0 for its real component. 
     /// 
     /// ```
     /// use complex::{Complex, Fraction};
     /// 
-    /// let real = Fraction::unchecked_from(1, 2);
-    /// let imaginary = Fraction::unchecked_from(3, 4);
+    /// let imaginary = 10;
     /// 
-    /// let complex = Complex::from(real, imaginary);
+    /// let complex = Complex::from_i32_imaginary(imaginary);
     /// 
-    /// assert_eq!(complex.get_imaginary(), imaginary);
+    /// assert_eq!(complex.get_imaginary(), Fraction::from_i32(imaginary));
     /// ```
-    pub const fn get_imaginary(&self) -> Fraction
+    pub const fn from_i32_imaginary(value: i32) -> Complex
     {
-        self.imaginary
+        Complex::from_i32_pair(0, value)
+    }
+
+    /// Creates a complex number with the given integer argumments for
+    /// its real and imaginary components. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let real = 10;
+    /// let imaginary = 4;
+    /// 
+    /// let complex = Complex::from_i32_pair(real, imaginary);
+    /// 
+    /// assert_eq!(complex.get_components(), (Fraction::from_i32(real), Fraction::from_i32(imaginary)));
+    /// ```
+    pub const fn from_i32_pair(real: i32, imaginary: i32) -> Complex
+    {
+        Complex {real: Fraction::from_i32(real), imaginary: Fraction::from_i32(imaginary)}
     }
 }
 
-impl std::fmt::Display for Complex
+impl<T: SignedInteger> std::fmt::Display for GenericComplex<GenericFraction<T>>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        if self.imaginary.get_numerator() == 0
+        if self.imaginary.get_numerator() == T::ZERO
         {
-            return  write!(f, "{}", self.real);
+            return write!(f, "{}", self.real);
         }
 
-        if self.real.get_numerator() == 0
+        if self.real.get_numerator() == T::ZERO
         {
             return write!(f, "{}i", self.imaginary);
         }
 
-        if self.imaginary >= Fraction::from_i32(0)
+        if self.imaginary >= GenericFraction::from_integer(T::ZERO)
         {
             return write!(f, "{} + {}i", self.real, self.imaginary);
         }
+
+        // The magnitude is written from the unsigned numerator, since `abs` would
+        // overflow for `T::MIN`.
+        let (numerator, denominator) = self.imaginary.get_components();
+
+        if denominator != T::Unsigned::ONE
+        {
+            write!(f, "{} - {}/{}i", self.real, numerator.unsigned_abs(), denominator)
+        }
         else
         {
-            return write!(f, "{} - {}i", self.real, self.imaginary.abs());
+            write!(f, "{} - {}i", self.real, numerator.unsigned_abs())
+        }
+    }
+}
+
+/// The error returned when parsing a complex number fails. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseComplexError
+{
+    /// The string was empty, or only contained whitespace. 
+    Empty,
+    /// One of the components could not be parsed as a fraction. 
+    InvalidFraction(ParseFractionError),
+    /// A character was found at `position` that is not valid there. 
+    InvalidCharacter {position: usize},
+    /// The string ended at `position` before the number was complete, as in `"(1, 2"`. 
+    UnexpectedEnd {position: usize},
+    /// A second real or a second imaginary term starts at `position`, as in `"1 + 2"`. 
+    RepeatedComponent {position: usize},
+    /// The component starting at `position` can not be represented with the integer
+    /// type of the complex number. 
+    Overflow {position: usize},
+}
+
+impl From<ParseFractionError> for ParseComplexError
+{
+    fn from(error: ParseFractionError) -> Self
+    {
+        match error.kind()
+        {
+            ParseFractionErrorKind::Overflow => ParseComplexError::Overflow {position: error.position()},
+            _ => ParseComplexError::InvalidFraction(error),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseComplexError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ParseComplexError::Empty => write!(f, "cannot parse complex number from empty string"),
+            ParseComplexError::InvalidFraction(error) => write!(f, "invalid component: {error}"),
+            ParseComplexError::InvalidCharacter {position} => write!(f, "invalid character in complex number at byte {position}"),
+            ParseComplexError::UnexpectedEnd {position} => write!(f, "complex number ended unexpectedly at byte {position}"),
+            ParseComplexError::RepeatedComponent {position} => write!(f, "repeated real or imaginary component at byte {position}"),
+            ParseComplexError::Overflow {position} => write!(f, "component is too large to fit in target type at byte {position}"),
         }
     }
 }
 
-impl PartialEq for Complex
+impl<T: SignedInteger> std::str::FromStr for GenericComplex<GenericFraction<T>>
+{
+    type Err = ParseComplexError;
+
+    /// Parses a complex number written as a sum of a real and an imaginary term, such
+    /// as `3/4 - 2/5i`, where either term can be left out and the imaginary unit can be
+    /// written as `i` or `j`, or as a tuple `(real, imaginary)`. 
+    /// Each component can be written in any form that `GenericFraction` accepts, and
+    /// the output of `Display` always parses back to an equal value. 
+    /// 
+    /// The polar form `r∠θ` is also accepted, with the angle in radians, or in degrees
+    /// when followed by `°`. 
+    /// Angles that are a multiple of a right angle give exact components, and any other
+    /// angle gives components that are rounded to the closest representable fractions. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let value = Complex::from(Fraction::unchecked_from(3, 4), Fraction::unchecked_from(-2, 5));
+    /// 
+    /// assert_eq!("3/4 - 2/5i".parse(), Ok(value));
+    /// assert_eq!(value.to_string().parse(), Ok(value));
+    /// 
+    /// assert_eq!("i".parse(), Ok(Complex::from_i32_imaginary(1)));
+    /// assert_eq!("-i".parse(), Ok(Complex::from_i32_imaginary(-1)));
+    /// assert_eq!("2j".parse(), Ok(Complex::from_i32_imaginary(2)));
+    /// assert_eq!("(1, 2)".parse(), Ok(Complex::from_i32_pair(1, 2)));
+    /// assert_eq!("0.5 + 1 1/2i".parse(), Ok(Complex::from(Fraction::unchecked_from(1, 2), Fraction::unchecked_from(3, 2))));
+    /// 
+    /// assert_eq!("2∠90°".parse(), Ok(Complex::from_i32_imaginary(2)));
+    /// assert_eq!("1/2 ∠ -540°".parse(), Ok(Complex::from_fraction(Fraction::unchecked_from(-1, 2))));
+    /// 
+    /// let rotated: Complex = "2∠60°".parse().unwrap();
+    /// 
+    /// assert_eq!(rotated.get_real(), Fraction::ONE);
+    /// assert!((rotated.get_imaginary().to_f64() - 3f64.sqrt()).abs() < 1e-9);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        // see the `FromStr` implementation of `GenericFraction` for the exponent limit
+        let parsed = parse_complex(s, 40)?;
+
+        let component = |value: BigFraction, position: usize|
+        {
+            let component = if parsed.exact
+            {
+                GenericFraction::try_from(value).ok()
+            }
+            else
+            {
+                // the components of most polar forms are irrational, so they are rounded
+                // to the closest fractions that can be represented
+                GenericFraction::from_big_limited(&value, T::Unsigned::MAX)
+            };
+
+            component.ok_or(ParseComplexError::Overflow {position})
+        };
+
+        let [real_position, imaginary_position] = parsed.positions;
+        let real = component(parsed.value.real, real_position)?;
+        let imaginary = component(parsed.value.imaginary, imaginary_position)?;
+
+        Ok(GenericComplex::from(real, imaginary))
+    }
+}
+
+impl<F: PartialEq> PartialEq for GenericComplex<F>
 {
     fn eq(&self, other: &Self) -> bool
     {
@@ -201,19 +384,51 @@ impl PartialEq for Complex
     }
 }
 
-impl Eq for Complex {}
+impl<F: Eq> Eq for GenericComplex<F> {}
 
-impl std::ops::Add<Complex> for Complex
+impl<T: SignedInteger> std::hash::Hash for GenericComplex<GenericFraction<T>>
 {
-    type Output = Complex;
+    /// Hashes both components in their simplified form, so that complex numbers that are
+    /// equal have the same hash. 
+    /// 
+    /// ```
+    /// use std::collections::HashMap;
+    /// 
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let mut names = HashMap::new();
+    /// 
+    /// names.insert(Complex::I, "i");
+    /// 
+    /// assert_eq!(names.get(&Complex::from(Fraction::unchecked_from(0, 5), Fraction::unchecked_from(2, 2))), Some(&"i"));
+    /// ```
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H)
+    {
+        self.real.hash(state);
+        self.imaginary.hash(state);
+    }
+}
 
-    fn add(self, rhs: Complex) -> Self::Output
+impl<T: SignedInteger> Default for GenericComplex<GenericFraction<T>>
+{
+    /// Returns `GenericComplex::ZERO`. 
+    fn default() -> Self
     {
-        Complex::from(self.real + rhs.real, self.imaginary + rhs.imaginary)
+        GenericComplex::ZERO
     }
 }
 
-impl std::ops::AddAssign for Complex
+impl<T: SignedInteger> std::ops::Add<GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
+{
+    type Output = GenericComplex<GenericFraction<T>>;
+
+    fn add(self, rhs: GenericComplex<GenericFraction<T>>) -> Self::Output
+    {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::ops::AddAssign for GenericComplex<GenericFraction<T>>
 {
     fn add_assign(&mut self, rhs: Self)
     {
@@ -221,27 +436,37 @@ impl std::ops::AddAssign for Complex
     }
 }
 
-impl std::ops::Neg for Complex
+impl<F: std::ops::Neg<Output = F>> std::ops::Neg for GenericComplex<F>
 {
-    type Output = Complex;
+    type Output = GenericComplex<F>;
 
     fn neg(self) -> Self::Output
     {
-        Complex::from(-self.real, -self.imaginary)
+        GenericComplex::from(-self.real, -self.imaginary)
     }
 }
 
-impl std::ops::Sub<Complex> for Complex
+impl<F: Clone + std::ops::Neg<Output = F>> std::ops::Neg for &GenericComplex<F>
 {
-    type Output = Complex;
+    type Output = GenericComplex<F>;
 
-    fn sub(self, rhs: Complex) -> Self::Output
+    fn neg(self) -> Self::Output
     {
-        self + (-rhs)
+        -self.clone()
     }
 }
 
-impl std::ops::SubAssign for Complex
+impl<T: SignedInteger> std::ops::Sub<GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
+{
+    type Output = GenericComplex<GenericFraction<T>>;
+
+    fn sub(self, rhs: GenericComplex<GenericFraction<T>>) -> Self::Output
+    {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::ops::SubAssign for GenericComplex<GenericFraction<T>>
 {
     fn sub_assign(&mut self, rhs: Self)
     {
@@ -249,21 +474,17 @@ impl std::ops::SubAssign for Complex
     }
 }
 
-impl std::ops::Mul<Complex> for Complex
+impl<T: SignedInteger> std::ops::Mul<GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
 {
-    type Output = Complex;
+    type Output = GenericComplex<GenericFraction<T>>;
 
-    fn mul(self, rhs: Complex) -> Self::Output
+    fn mul(self, rhs: GenericComplex<GenericFraction<T>>) -> Self::Output
     {
-        Complex::from
-        (
-            self.real * rhs.real - self.imaginary * rhs.imaginary,
-            self.real * rhs.imaginary + self.imaginary * rhs.real
-        )
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::MulAssign for Complex
+impl<T: SignedInteger> std::ops::MulAssign for GenericComplex<GenericFraction<T>>
 {
     fn mul_assign(&mut self, rhs: Self)
     {
@@ -271,7 +492,7 @@ impl std::ops::MulAssign for Complex
     }
 }
 
-impl Complex
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
 {
     /// Returns the complex conjugate of the number. 
     /// 
@@ -282,28 +503,39 @@ impl Complex
     /// 
     /// assert_eq!(complex.conjugate(), Complex::from_i32_pair(1, -5));
     /// ```
-    pub fn conjugate(self) -> Complex
+    pub fn conjugate(self) -> GenericComplex<GenericFraction<T>>
     {
-        Complex::from(self.real, -self.imaginary)
+        GenericComplex::from(self.real, -self.imaginary)
+    }
+
+    /// Applies `GenericFraction::limit_denominator` to both components. 
+    /// 
+    /// Panics if `max_denominator` is 0. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let value = Complex::from(Fraction::unchecked_from(333, 1000), Fraction::unchecked_from(-142857, 1000000));
+    /// 
+    /// assert_eq!(value.limit_denominator(10), Complex::from(Fraction::unchecked_from(1, 3), Fraction::unchecked_from(-1, 7)));
+    /// ```
+    pub fn limit_denominator(self, max_denominator: T::Unsigned) -> GenericComplex<GenericFraction<T>>
+    {
+        GenericComplex::from(self.real.limit_denominator(max_denominator), self.imaginary.limit_denominator(max_denominator))
     }
 }
 
-impl std::ops::Div<Complex> for Complex
+impl<T: SignedInteger> std::ops::Div<GenericComplex<GenericFraction<T>>> for GenericComplex<GenericFraction<T>>
 {
-    type Output = Complex;
+    type Output = GenericComplex<GenericFraction<T>>;
 
-    fn div(self, rhs: Complex) -> Self::Output
+    fn div(self, rhs: GenericComplex<GenericFraction<T>>) -> Self::Output
     {
-        let numerator = self * rhs.conjugate();
-
-        // Product of rhs and rhs.conjugate()
-        let denominator = rhs.real * rhs.real + rhs.imaginary * rhs.imaginary;
-
-        Complex::from(numerator.real / denominator, numerator.imaginary / denominator)
+        self.checked_div(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::DivAssign for Complex
+impl<T: SignedInteger> std::ops::DivAssign for GenericComplex<GenericFraction<T>>
 {
     fn div_assign(&mut self, rhs: Self)
     {
@@ -311,7 +543,435 @@ impl std::ops::DivAssign for Complex
     }
 }
 
-impl Complex
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
+{
+    /// Adds two complex numbers, returning `ArithmeticError::Overflow` if either
+    /// component of the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Complex::from_i32_pair(1, 2);
+    /// 
+    /// assert_eq!(a.checked_add(a), Ok(Complex::from_i32_pair(2, 4)));
+    /// assert_eq!(Complex::from_i32(i32::MAX).checked_add(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_add(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericComplex::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, returning `ArithmeticError::Overflow` if either
+    /// component of the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Complex::from_i32_pair(1, 2);
+    /// 
+    /// assert_eq!(a.checked_sub(Complex::from_i32(3)), Ok(Complex::from_i32_pair(-2, 2)));
+    /// assert_eq!(Complex::from_i32_imaginary(i32::MIN).checked_sub(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_sub(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericComplex::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two complex numbers, returning `ArithmeticError::Overflow` if either
+    /// component of the result can not be represented. 
+    /// Intermediate products that overflow do not cause an error if the final result fits. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Complex::from_i32_pair(65536, 65536);
+    /// let b = Complex::from_i32_pair(65536, -65536);
+    /// 
+    /// assert_eq!(a.checked_mul(Complex::from_i32_imaginary(1)), Ok(Complex::from_i32_pair(-65536, 65536)));
+    /// assert_eq!(a.checked_mul(b), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_mul(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericComplex::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, returning `ArithmeticError::DivByZero` if `rhs` is 0
+    /// and `ArithmeticError::Overflow` if either component of the result can not be
+    /// represented. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Complex::from_i32_pair(20, -4);
+    /// 
+    /// assert_eq!(a.checked_div(Complex::from_i32_pair(3, 2)), Ok(Complex::from_i32_pair(4, -4)));
+    /// assert_eq!(a.checked_div(Complex::from_i32(0)), Err(ArithmeticError::DivByZero));
+    /// 
+    /// // the exact result i/2^31 fits, even though the conjugate of the divisor does not
+    /// let divisor = Complex::from_i32_imaginary(i32::MIN);
+    /// 
+    /// assert_eq!(Complex::from_i32(1).checked_div(divisor), Ok(Complex::from_fraction_imaginary(Fraction::unchecked_from(1, 1 << 31))));
+    /// ```
+    pub fn checked_div(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        if rhs.is_zero()
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        self.checked_operation(rhs, GenericComplex::div_fast, |a, b| a / b)
+    }
+
+    /// Negates both components, returning `ArithmeticError::Overflow` if either of them
+    /// can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// assert_eq!(Complex::from_i32_pair(1, -2).checked_neg(), Ok(Complex::from_i32_pair(-1, 2)));
+    /// assert_eq!(Complex::from_i32_imaginary(i32::MIN).checked_neg(), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_neg(self) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        Ok(GenericComplex::from(self.real.checked_neg()?, self.imaginary.checked_neg()?))
+    }
+
+    /// Raises the complex number to the power of `exponent` by repeated squaring,
+    /// taking the reciprocal for negative exponents. 
+    /// Returns `ArithmeticError::DivByZero` if 0 is raised to a negative power, and
+    /// `ArithmeticError::Overflow` if any of the intermediate powers can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let value = Complex::from_i32_pair(1, 1);
+    /// 
+    /// assert_eq!(value.checked_pow(4), Ok(Complex::from_i32(-4)));
+    /// assert_eq!(Complex::from_i32_imaginary(2).checked_pow(-2), Ok(Complex::from_fraction("-1/4".parse().unwrap())));
+    /// assert_eq!(value.checked_pow(80), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_pow(self, exponent: i32) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        let one = GenericComplex::from_integer_pair(T::ONE, T::ZERO);
+
+        let mut base = if exponent < 0 { one.checked_div(self)? } else { self };
+
+        let mut result = one;
+        let mut exponent = exponent.unsigned_abs();
+
+        while exponent > 0
+        {
+            if exponent & 1 == 1
+            {
+                result = result.checked_mul(base)?;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0
+            {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Adds two complex numbers, saturating each component at the numeric bounds
+    /// instead of overflowing. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MAX, 1);
+    /// 
+    /// assert_eq!(a.saturating_add(a), Complex::from_i32_pair(i32::MAX, 2));
+    /// ```
+    pub fn saturating_add(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.saturating_operation(rhs, GenericComplex::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, saturating each component at the numeric bounds
+    /// instead of overflowing. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MIN, 1);
+    /// 
+    /// assert_eq!(a.saturating_sub(Complex::from_i32_pair(1, 1)), Complex::from_i32_pair(i32::MIN, 0));
+    /// ```
+    pub fn saturating_sub(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.saturating_operation(rhs, GenericComplex::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two complex numbers, saturating each component at the numeric bounds
+    /// instead of overflowing. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(65536, 65536);
+    /// 
+    /// assert_eq!(a.saturating_mul(a), Complex::from_i32_pair(0, i32::MAX));
+    /// ```
+    pub fn saturating_mul(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.saturating_operation(rhs, GenericComplex::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, saturating each component at the numeric bounds
+    /// instead of overflowing. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MAX, 2);
+    /// let half = Complex::from_fraction(Fraction::unchecked_from(1, 2));
+    /// 
+    /// assert_eq!(a.saturating_div(half), Complex::from_i32_pair(i32::MAX, 4));
+    /// ```
+    pub fn saturating_div(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        self.saturating_operation(rhs, GenericComplex::div_fast, |a, b| a / b)
+    }
+
+    /// Adds two complex numbers, wrapping the components of the exact result around
+    /// at the bounds of their types. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MAX, 1);
+    /// 
+    /// assert_eq!(a.wrapping_add(Complex::from_i32(1)), Complex::from_i32_pair(i32::MIN, 1));
+    /// ```
+    pub fn wrapping_add(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtracts `rhs` from `self`, wrapping the components of the exact result around
+    /// at the bounds of their types. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MIN, 1);
+    /// 
+    /// assert_eq!(a.wrapping_sub(Complex::from_i32(1)), Complex::from_i32_pair(i32::MAX, 1));
+    /// ```
+    pub fn wrapping_sub(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiplies two complex numbers, wrapping the components of the exact result
+    /// around at the bounds of their types. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(65536, 65536);
+    /// 
+    /// assert_eq!(a.wrapping_mul(a), Complex::from_i32(0));
+    /// ```
+    pub fn wrapping_mul(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Divides `self` by `rhs`, wrapping the components of the exact result around
+    /// at the bounds of their types. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MIN, 2);
+    /// 
+    /// assert_eq!(a.wrapping_div(Complex::from_i32(-1)), Complex::from_i32_pair(i32::MIN, -2));
+    /// ```
+    pub fn wrapping_div(self, rhs: GenericComplex<GenericFraction<T>>) -> GenericComplex<GenericFraction<T>>
+    {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Adds two complex numbers, returning the result of `wrapping_add` along with a
+    /// boolean indicating whether either component overflowed. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(i32::MAX, 1);
+    /// 
+    /// assert_eq!(a.overflowing_add(Complex::from_i32(1)), (Complex::from_i32_pair(i32::MIN, 1), true));
+    /// ```
+    pub fn overflowing_add(self, rhs: GenericComplex<GenericFraction<T>>) -> (GenericComplex<GenericFraction<T>>, bool)
+    {
+        self.overflowing_operation(rhs, GenericComplex::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, returning the result of `wrapping_sub` along with
+    /// a boolean indicating whether either component overflowed. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(1, 1);
+    /// 
+    /// assert_eq!(a.overflowing_sub(a), (Complex::from_i32(0), false));
+    /// ```
+    pub fn overflowing_sub(self, rhs: GenericComplex<GenericFraction<T>>) -> (GenericComplex<GenericFraction<T>>, bool)
+    {
+        self.overflowing_operation(rhs, GenericComplex::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two complex numbers, returning the result of `wrapping_mul` along
+    /// with a boolean indicating whether either component overflowed. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(65536, 65536);
+    /// 
+    /// assert_eq!(a.overflowing_mul(a), (Complex::from_i32(0), true));
+    /// ```
+    pub fn overflowing_mul(self, rhs: GenericComplex<GenericFraction<T>>) -> (GenericComplex<GenericFraction<T>>, bool)
+    {
+        self.overflowing_operation(rhs, GenericComplex::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, returning the result of `wrapping_div` along with a
+    /// boolean indicating whether either component overflowed. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// let a = Complex::from_i32_pair(20, -4);
+    /// 
+    /// assert_eq!(a.overflowing_div(Complex::from_i32_pair(3, 2)), (Complex::from_i32_pair(4, -4), false));
+    /// ```
+    pub fn overflowing_div(self, rhs: GenericComplex<GenericFraction<T>>) -> (GenericComplex<GenericFraction<T>>, bool)
+    {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        self.overflowing_operation(rhs, GenericComplex::div_fast, |a, b| a / b)
+    }
+
+    /// Applies `fast` to the operands, falling back to computing the exact result
+    /// with `exact` if any intermediate value overflows. 
+    fn checked_operation
+    (
+        self,
+        rhs: GenericComplex<GenericFraction<T>>,
+        fast: fn(Self, Self) -> Result<Self, ArithmeticError>,
+        exact: fn(BigComplex, BigComplex) -> BigComplex,
+    ) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        match fast(self, rhs)
+        {
+            Err(ArithmeticError::Overflow) =>
+                GenericComplex::try_from(exact(self.into(), rhs.into())).map_err(|_| ArithmeticError::Overflow),
+            result => result,
+        }
+    }
+
+    fn saturating_operation
+    (
+        self,
+        rhs: GenericComplex<GenericFraction<T>>,
+        fast: fn(Self, Self) -> Result<Self, ArithmeticError>,
+        exact: fn(BigComplex, BigComplex) -> BigComplex,
+    ) -> GenericComplex<GenericFraction<T>>
+    {
+        fast(self, rhs).unwrap_or_else(|_|
+        {
+            let exact = exact(self.into(), rhs.into());
+            let (real, imaginary) = exact.get_components();
+
+            GenericComplex::from(GenericFraction::from_big_saturating(real), GenericFraction::from_big_saturating(imaginary))
+        })
+    }
+
+    fn overflowing_operation
+    (
+        self,
+        rhs: GenericComplex<GenericFraction<T>>,
+        fast: fn(Self, Self) -> Result<Self, ArithmeticError>,
+        exact: fn(BigComplex, BigComplex) -> BigComplex,
+    ) -> (GenericComplex<GenericFraction<T>>, bool)
+    {
+        match fast(self, rhs)
+        {
+            Ok(value) => (value, false),
+            Err(_) =>
+            {
+                let exact = exact(self.into(), rhs.into());
+                let (real, imaginary) = exact.get_components();
+
+                let (real, real_overflowed) = GenericFraction::from_big_wrapping(real);
+                let (imaginary, imaginary_overflowed) = GenericFraction::from_big_wrapping(imaginary);
+
+                (GenericComplex::from(real, imaginary), real_overflowed || imaginary_overflowed)
+            }
+        }
+    }
+
+    fn add_fast(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        Ok(GenericComplex::from(self.real.checked_add(rhs.real)?, self.imaginary.checked_add(rhs.imaginary)?))
+    }
+
+    fn sub_fast(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        Ok(GenericComplex::from(self.real.checked_sub(rhs.real)?, self.imaginary.checked_sub(rhs.imaginary)?))
+    }
+
+    fn mul_fast(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        Ok(GenericComplex::from
+        (
+            self.real.checked_mul(rhs.real)?.checked_sub(self.imaginary.checked_mul(rhs.imaginary)?)?,
+            self.real.checked_mul(rhs.imaginary)?.checked_add(self.imaginary.checked_mul(rhs.real)?)?
+        ))
+    }
+
+    fn div_fast(self, rhs: GenericComplex<GenericFraction<T>>) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        // the conjugate is negated with a check, since `T::MIN` can not be negated
+        let conjugate = GenericComplex::from(rhs.real, rhs.imaginary.checked_neg()?);
+        let numerator = self.mul_fast(conjugate)?;
+
+        // Product of rhs and rhs.conjugate()
+        let denominator = rhs.real.checked_mul(rhs.real)?.checked_add(rhs.imaginary.checked_mul(rhs.imaginary)?)?;
+
+        Ok(GenericComplex::from(numerator.real.checked_div(denominator)?, numerator.imaginary.checked_div(denominator)?))
+    }
+
+    /// Returns true if both components are 0. 
+    fn is_zero(&self) -> bool
+    {
+        self.real.get_numerator() == T::ZERO && self.imaginary.get_numerator() == T::ZERO
+    }
+}
+
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
 {
     /// Returns a complex number representing the real and imaginary signs of this value. 
     /// 
@@ -324,20 +984,198 @@ impl Complex
     /// 
     /// assert_eq!(value.signum(), Complex::from_i32_pair(value.get_real().signum(), value.get_imaginary().signum()));
     /// ```
-    pub const fn signum(self) -> Complex
+    pub fn signum(self) -> GenericComplex<GenericFraction<T>>
     {
-        Complex::from_i32_pair(self.real.signum(), self.imaginary.signum())
+        GenericComplex::from_integer_pair(self.real.signum(), self.imaginary.signum())
     }
 
     /// Returns the absolute value of this complex number, squared. 
-    pub fn abs_squared(self) -> Fraction
+    pub fn abs_squared(self) -> GenericFraction<T>
     {
         self.real * self.real + self.imaginary * self.imaginary
     }
 
     /// Returns the absolute value of this complex number. 
-    pub fn abs(self) -> Fraction
+    pub fn abs(self) -> GenericFraction<T>
     {
         self.abs_squared().sqrt().real
     }
+
+    /// Raises the complex number to the power of `exponent` by repeated squaring,
+    /// taking the reciprocal for negative exponents. 
+    /// 
+    /// Panics if the result overflows, or if 0 is raised to a negative power. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let value = Complex::from_i32_pair(1, 1);
+    /// 
+    /// assert_eq!(value.pow(2), Complex::from_i32_imaginary(2));
+    /// assert_eq!(value.pow(-2), Complex::from_fraction_imaginary(Fraction::unchecked_from(-1, 2)));
+    /// ```
+    pub fn pow(self, exponent: i32) -> GenericComplex<GenericFraction<T>>
+    {
+        self.checked_pow(exponent).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns the exact principal `n`th root of the complex number, which is the root
+    /// with the smallest angle from the positive real axis, or `None` if it is not
+    /// rational or can not be represented. 
+    /// Unlike `GenericFraction::nth_root`, negative real numbers give the principal
+    /// root, so the cube root of -8 is `None` rather than -2. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// assert_eq!(Complex::from_i32_pair(3, 4).nth_root(2), Some(Complex::from_i32_pair(2, 1)));
+    /// assert_eq!(Complex::from_i32(-4).nth_root(2), Some(Complex::from_i32_imaginary(2)));
+    /// assert_eq!(Complex::from_i32_pair(-117, 44).nth_root(3), Some(Complex::from_i32_pair(3, 4)));
+    /// assert_eq!(Complex::from_fraction_imaginary(Fraction::unchecked_from(1, 2)).nth_root(2), Some(Complex::from(Fraction::unchecked_from(1, 2), Fraction::unchecked_from(1, 2))));
+    /// assert_eq!(Complex::from_i32(-8).nth_root(3), None);
+    /// assert_eq!(Complex::I.nth_root(2), None);
+    /// ```
+    pub fn nth_root(self, n: u32) -> Option<GenericComplex<GenericFraction<T>>>
+    {
+        if n == 0 || self.real.get_denominator() == T::Unsigned::ZERO || self.imaginary.get_denominator() == T::Unsigned::ZERO
+        {
+            return None;
+        }
+
+        let value = GenericComplex::from(self.real.simplify(), self.imaginary.simplify());
+
+        if n == 1 || value == GenericComplex::ZERO
+        {
+            return Some(value);
+        }
+
+        // a root other than 0 or a unit has a norm of at least 2 or a denominator whose
+        // `n`th power is at least 2^(n/2), neither of which fit in `T` for larger `n`,
+        // and the principal root of a unit is only rational for 1
+        let bits = T::Unsigned::MAX.to_f64().log2().round() as u32;
+
+        if n > 4 * bits
+        {
+            return (value == GenericComplex::ONE).then_some(value);
+        }
+
+        // starts from the principal root in floating point
+        let radius = value.real.to_f64().hypot(value.imaginary.to_f64()).powf(1.0 / n as f64);
+        let angle = value.imaginary.to_f64().atan2(value.real.to_f64()) / n as f64;
+        let (real, imaginary) = (radius * angle.cos(), radius * angle.sin());
+
+        // a root with a denominator of m has a power with a denominator of at least
+        // m^(n/2), so for larger `n` the denominator is small enough for the root to be
+        // found from the floating point estimate directly
+        if n > bits / 4
+        {
+            let bound = T::Unsigned::from_f64(2f64.powi((4 * bits).div_ceil(n) as i32).min(T::Unsigned::MAX.to_f64()));
+            let root = GenericComplex::from(GenericFraction::approximate_f64(real, bound)?, GenericFraction::approximate_f64(imaginary, bound)?);
+
+            return (root.checked_pow(n as i32) == Ok(value)).then_some(root);
+        }
+
+        // with `value` as `beta / d` for a gaussian integer `beta`, the root is `gamma / d`
+        // for a gaussian integer `gamma` with `gamma^n = beta * d^(n - 1)`, which Newton's
+        // method finds from the estimate while rounding to gaussian integers
+        let big: BigComplex = value.into();
+        let (real_denominator, imaginary_denominator) = (big.real.get_denominator(), big.imaginary.get_denominator());
+        let d = BigFraction::from_integer((&(real_denominator / &real_denominator.gcd(imaginary_denominator)) * imaginary_denominator).into());
+
+        let target = &(&big * &BigComplex::from_fraction(d.clone())) * &big_pow(&BigComplex::from_fraction(d.clone()), n - 1);
+
+        let estimate = BigComplex::from(BigFraction::try_from(real).ok()?, BigFraction::try_from(imaginary).ok()?);
+        let mut gamma = round_gaussian(&(&estimate * &BigComplex::from_fraction(d.clone())));
+
+        // the estimate is close enough to an exact root that each step only corrects what
+        // floating point could not resolve, so a larger step means there is no exact root
+        let tolerance = BigFraction::from_integer(BigInt::from(1u128 << 64));
+
+        for _ in 0..64
+        {
+            let power = big_pow(&gamma, n - 1);
+
+            if power.real.get_numerator().is_zero() && power.imaginary.get_numerator().is_zero()
+            {
+                return None;
+            }
+
+            let derivative = &power * &BigComplex::from_fraction(BigFraction::from_integer(n.into()));
+            let next = round_gaussian(&(&gamma - &(&(&(&power * &gamma) - &target) / &derivative)));
+
+            if next == gamma
+            {
+                break;
+            }
+
+            if &(&next - &gamma).abs_squared() * &tolerance > gamma.abs_squared()
+            {
+                return None;
+            }
+
+            gamma = next;
+        }
+
+        if big_pow(&gamma, n) != target
+        {
+            return None;
+        }
+
+        let root = BigComplex::from(&gamma.real / &d, &gamma.imaginary / &d);
+
+        GenericComplex::try_from(root).ok()
+    }
+
+    /// Raises the complex number to a rational power, by taking the principal root for
+    /// the denominator of `exponent` and then raising it to the power of the numerator. 
+    /// Returns `None` if the result is not rational, or can not be represented. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let value = Complex::from_i32_imaginary(2);
+    /// 
+    /// assert_eq!(value.pow_frac(Fraction::unchecked_from(3, 2)), Some(Complex::from_i32_pair(-2, 2)));
+    /// assert_eq!(Complex::from_i32(-4).pow_frac(Fraction::unchecked_from(-1, 2)), Some(Complex::from_fraction_imaginary(Fraction::unchecked_from(-1, 2))));
+    /// assert_eq!(value.pow_frac(Fraction::unchecked_from(1, 3)), None);
+    /// ```
+    pub fn pow_frac(self, exponent: GenericFraction<T>) -> Option<GenericComplex<GenericFraction<T>>>
+    {
+        let exponent = GenericFraction::<T>::from(exponent.get_numerator(), exponent.get_denominator()).ok()?;
+
+        let power = i32::try_from(exponent.get_numerator().into()).ok()?;
+        let root = u32::try_from(exponent.get_denominator().into()).ok()?;
+
+        self.nth_root(root)?.checked_pow(power).ok()
+    }
+}
+
+/// Raises `value` to the power of `exponent` by repeated squaring. 
+fn big_pow(value: &BigComplex, mut exponent: u32) -> BigComplex
+{
+    let mut base = value.clone();
+    let mut result = BigComplex::from_fraction(BigFraction::from_integer(BigInt::one()));
+
+    while exponent > 0
+    {
+        if exponent & 1 == 1
+        {
+            result = &result * &base;
+        }
+
+        exponent >>= 1;
+
+        if exponent > 0
+        {
+            base = &base * &base;
+        }
+    }
+
+    result
+}
+
+/// Rounds both components of `value` to the nearest integer. 
+fn round_gaussian(value: &BigComplex) -> BigComplex
+{
+    BigComplex::from(BigFraction::from_integer(round_big(&value.real, RoundingMode::HalfEven)), BigFraction::from_integer(round_big(&value.imaginary, RoundingMode::HalfEven)))
 }
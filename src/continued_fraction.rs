@@ -0,0 +1,383 @@
+use std::marker::PhantomData;
+
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::fraction::{ArithmeticError, FloatConversionError, GenericFraction};
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// A lazy iterator over the terms of the simple continued fraction of an exact rational
+/// value, starting with its floor, followed by terms that are all positive. 
+/// The last term is never 1, unless it is the only term. 
+/// 
+/// ```
+/// use complex::BigInt;
+/// use complex::continued_fraction::Expansion;
+/// 
+/// let terms: Vec<BigInt> = Expansion::from_f64(-0.375).unwrap().collect();
+/// 
+/// assert_eq!(terms, [BigInt::from(-1), BigInt::from(1), BigInt::from(1), BigInt::from(1), BigInt::from(2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Expansion
+{
+    /// The value that is still left to expand is `numerator / denominator`. 
+    numerator: BigInt,
+    denominator: BigUint,
+}
+
+impl Expansion
+{
+    /// Creates the expansion of a fraction, which is empty if its denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{BigInt, Fraction};
+    /// use complex::continued_fraction::Expansion;
+    /// 
+    /// let terms: Vec<BigInt> = Expansion::from_fraction(Fraction::unchecked_from(45, 16)).collect();
+    /// 
+    /// assert_eq!(terms, [BigInt::from(2), BigInt::from(1), BigInt::from(4), BigInt::from(3)]);
+    /// ```
+    pub fn from_fraction<T: SignedInteger>(value: GenericFraction<T>) -> Expansion
+    {
+        let (numerator, denominator) = value.get_components();
+
+        Expansion
+        {
+            numerator: BigInt::from(numerator.into()),
+            denominator: BigUint::from(denominator.into()),
+        }
+    }
+
+    /// Creates the expansion of the exact value of a float, returning an error for NaN
+    /// and infinity. 
+    /// Floats are always rational, so the expansion ends, but it follows the irrational
+    /// number that the float approximates for as many terms as the float is precise. 
+    /// 
+    /// ```
+    /// use complex::BigInt;
+    /// use complex::continued_fraction::Expansion;
+    /// 
+    /// let terms: Vec<BigInt> = Expansion::from_f64(std::f64::consts::PI).unwrap().take(5).collect();
+    /// 
+    /// assert_eq!(terms, [3, 7, 15, 1, 292].map(BigInt::from));
+    /// ```
+    pub fn from_f64(value: f64) -> Result<Expansion, FloatConversionError>
+    {
+        let value = BigFraction::try_from(value)?;
+
+        Ok(Expansion {numerator: value.get_numerator().clone(), denominator: value.get_denominator().clone()})
+    }
+}
+
+impl Iterator for Expansion
+{
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.denominator.is_zero()
+        {
+            return None;
+        }
+
+        let (quotient, remainder) = self.numerator.magnitude().div_rem(&self.denominator);
+
+        // the first term is the floor of the value, and every other value is positive
+        let (term, remainder) = if !self.numerator.is_negative()
+        {
+            (BigInt::from(quotient), remainder)
+        }
+        else if remainder.is_zero()
+        {
+            (-BigInt::from(quotient), remainder)
+        }
+        else
+        {
+            (-BigInt::from(quotient + BigUint::one()), &self.denominator - &remainder)
+        };
+
+        self.numerator = BigInt::from(std::mem::replace(&mut self.denominator, remainder));
+
+        Some(term)
+    }
+}
+
+/// The numerators and denominators of the previous two convergents, starting from the
+/// values `0/1` and `1/0` that come before the first term. 
+#[derive(Clone, Debug)]
+struct Recurrence
+{
+    previous: (BigInt, BigInt),
+    current: (BigInt, BigInt),
+}
+
+impl Recurrence
+{
+    fn new() -> Recurrence
+    {
+        Recurrence {previous: (BigInt::zero(), BigInt::one()), current: (BigInt::one(), BigInt::zero())}
+    }
+
+    /// Returns `previous + multiple * current`, which is the next convergent if `multiple`
+    /// is the next term, and a semiconvergent if it is smaller. 
+    fn combine(&self, multiple: &BigInt) -> (BigInt, BigInt)
+    {
+        (&self.previous.0 + multiple * &self.current.0, &self.previous.1 + multiple * &self.current.1)
+    }
+
+    fn advance(&mut self, term: &BigInt)
+    {
+        let next = self.combine(term);
+
+        self.previous = std::mem::replace(&mut self.current, next);
+    }
+}
+
+/// Converts a numerator and denominator of either sign to a fraction. 
+fn to_fraction<T: SignedInteger>((numerator, denominator): (BigInt, BigInt)) -> Result<GenericFraction<T>, ArithmeticError>
+{
+    let numerator = if denominator.is_negative() { -numerator } else { numerator };
+    let value = BigFraction::from(numerator, denominator.unsigned_abs())?;
+
+    GenericFraction::try_from(value).map_err(|_| ArithmeticError::Overflow)
+}
+
+/// An iterator over the convergents of a continued fraction, which are the fractions
+/// given by each prefix of its terms, see `convergents`. 
+#[derive(Clone, Debug)]
+pub struct Convergents<T: SignedInteger, I>
+{
+    terms: I,
+    recurrence: Recurrence,
+    finished: bool,
+    marker: PhantomData<T>,
+}
+
+/// Returns an iterator over the convergents of the continued fraction with the given
+/// terms, which are the best approximations of its value for the size of their denominators. 
+/// The iterator ends with the first convergent that does not fit in `GenericFraction<T>`. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// use complex::continued_fraction::{convergents, Expansion};
+/// 
+/// let pi = Expansion::from_f64(std::f64::consts::PI).unwrap();
+/// let approximations: Vec<Fraction> = convergents(pi).take(4).collect();
+/// 
+/// assert_eq!(approximations, [(3, 1), (22, 7), (333, 106), (355, 113)].map(|(n, d)| Fraction::unchecked_from(n, d)));
+/// 
+/// let golden: Vec<Fraction> = convergents([1; 6]).collect();
+/// 
+/// assert_eq!(golden.last(), Some(&Fraction::unchecked_from(13, 8)));
+/// ```
+pub fn convergents<T: SignedInteger, I>(terms: I) -> Convergents<T, I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Into<BigInt>,
+{
+    Convergents {terms: terms.into_iter(), recurrence: Recurrence::new(), finished: false, marker: PhantomData}
+}
+
+impl<T: SignedInteger, I> Iterator for Convergents<T, I>
+where
+    I: Iterator,
+    I::Item: Into<BigInt>,
+{
+    type Item = GenericFraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.finished
+        {
+            return None;
+        }
+
+        let Some(term) = self.terms.next() else
+        {
+            self.finished = true;
+            return None;
+        };
+
+        self.recurrence.advance(&term.into());
+
+        let convergent = to_fraction(self.recurrence.current.clone()).ok();
+
+        self.finished = convergent.is_none();
+
+        convergent
+    }
+}
+
+/// An iterator over the semiconvergents of a continued fraction, see `semiconvergents`. 
+#[derive(Clone, Debug)]
+pub struct Semiconvergents<T: SignedInteger, I>
+{
+    terms: I,
+    recurrence: Recurrence,
+    /// The current term, and the multiple of it that was last used. 
+    term: Option<(BigInt, BigInt)>,
+    started: bool,
+    finished: bool,
+    marker: PhantomData<T>,
+}
+
+/// Returns an iterator over the semiconvergents of the continued fraction with the given
+/// terms, in order of increasing denominators. 
+/// For each term `a` after the first, these are the fractions found by replacing `a` with
+/// each of `1, 2, ..., a` in the terms so far, so they include every convergent. 
+/// The iterator ends with the first fraction that does not fit in `GenericFraction<T>`. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// use complex::continued_fraction::semiconvergents;
+/// 
+/// // 7/16 = [0; 2, 3, 2]
+/// let fractions: Vec<Fraction> = semiconvergents([0, 2, 3, 2]).collect();
+/// 
+/// assert_eq!(fractions, [(0, 1), (1, 1), (1, 2), (1, 3), (2, 5), (3, 7), (4, 9), (7, 16)].map(|(n, d)| Fraction::unchecked_from(n, d)));
+/// ```
+pub fn semiconvergents<T: SignedInteger, I>(terms: I) -> Semiconvergents<T, I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Into<BigInt>,
+{
+    Semiconvergents
+    {
+        terms: terms.into_iter(),
+        recurrence: Recurrence::new(),
+        term: None,
+        started: false,
+        finished: false,
+        marker: PhantomData,
+    }
+}
+
+impl<T: SignedInteger, I> Iterator for Semiconvergents<T, I>
+where
+    I: Iterator,
+    I::Item: Into<BigInt>,
+{
+    type Item = GenericFraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.finished
+        {
+            return None;
+        }
+
+        let (term, multiple) = match self.term.take()
+        {
+            Some((term, multiple)) if multiple < term => (term, multiple + BigInt::one()),
+            _ =>
+            {
+                let Some(term) = self.terms.next().map(Into::into) else
+                {
+                    self.finished = true;
+                    return None;
+                };
+
+                // the first term only has its convergent, as do invalid terms below 1
+                let multiple = if self.started && !term.is_negative() && !term.is_zero() { BigInt::one() } else { term.clone() };
+
+                self.started = true;
+
+                (term, multiple)
+            }
+        };
+
+        let fraction = to_fraction(self.recurrence.combine(&multiple)).ok();
+
+        if multiple == term
+        {
+            self.recurrence.advance(&term);
+        }
+        else
+        {
+            self.term = Some((term, multiple));
+        }
+
+        self.finished = fraction.is_none();
+
+        fraction
+    }
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Returns the terms of the simple continued fraction of the fraction, where the first
+    /// term is its floor and every other term is positive. 
+    /// Returns `ArithmeticError::DivByZero` if the denominator is 0, and
+    /// `ArithmeticError::Overflow` if a term does not fit in `T`, which can only happen to
+    /// the second term when the denominator is larger than `T::MAX`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(415, 93).to_continued_fraction(), Ok(vec![4, 2, 6, 7]));
+    /// assert_eq!(Fraction::unchecked_from(-7, 3).to_continued_fraction(), Ok(vec![-3, 1, 2]));
+    /// ```
+    pub fn to_continued_fraction(&self) -> Result<Vec<T>, ArithmeticError>
+    {
+        if self.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        Expansion::from_fraction(*self)
+            .map(|term| term.to_i128().and_then(|term| T::try_from(term).ok()).ok_or(ArithmeticError::Overflow))
+            .collect()
+    }
+
+    /// Creates a fraction from the terms of a continued fraction. 
+    /// Returns `ArithmeticError::DivByZero` if there are no terms or the value has 0 for
+    /// its denominator, and `ArithmeticError::Overflow` if the value does not fit. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_continued_fraction(&[4, 2, 6, 7]), Ok(Fraction::unchecked_from(415, 93)));
+    /// assert_eq!(Fraction::from_continued_fraction(&[-3, 1, 2]), Ok(Fraction::unchecked_from(-7, 3)));
+    /// ```
+    pub fn from_continued_fraction(terms: &[T]) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        let mut recurrence = Recurrence::new();
+
+        for &term in terms
+        {
+            recurrence.advance(&BigInt::from(term.into()));
+        }
+
+        to_fraction(recurrence.current)
+    }
+
+    /// Returns an iterator over the convergents of the fraction, ending with the fraction
+    /// itself, see `continued_fraction::convergents`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let convergents: Vec<Fraction> = Fraction::unchecked_from(415, 93).convergents().collect();
+    /// 
+    /// assert_eq!(convergents, [(4, 1), (9, 2), (58, 13), (415, 93)].map(|(n, d)| Fraction::unchecked_from(n, d)));
+    /// ```
+    pub fn convergents(&self) -> Convergents<T, Expansion>
+    {
+        convergents(Expansion::from_fraction(*self))
+    }
+
+    /// Returns an iterator over the semiconvergents of the fraction, ending with the fraction
+    /// itself, see `continued_fraction::semiconvergents`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let semiconvergents: Vec<Fraction> = Fraction::unchecked_from(3, 7).semiconvergents().collect();
+    /// 
+    /// assert_eq!(semiconvergents, [(0, 1), (1, 1), (1, 2), (1, 3), (2, 5), (3, 7)].map(|(n, d)| Fraction::unchecked_from(n, d)));
+    /// ```
+    pub fn semiconvergents(&self) -> Semiconvergents<T, Expansion>
+    {
+        semiconvergents(Expansion::from_fraction(*self))
+    }
+}
@@ -0,0 +1,191 @@
+use crate::fraction::GenericFraction;
+use crate::integer::SignedInteger;
+use crate::rounding::RoundingMode;
+
+/// Produces the decimal digits of `remainder / divisor` one at a time, with exact integer
+/// long division. 
+struct LongDivision
+{
+    remainder: u128,
+    divisor: u128,
+}
+
+impl LongDivision
+{
+    /// Returns the integer part of `dividend / divisor`, along with a `LongDivision`
+    /// for the fractional part. 
+    fn new(dividend: u128, divisor: u128) -> (u128, LongDivision)
+    {
+        (dividend / divisor, LongDivision {remainder: dividend % divisor, divisor})
+    }
+
+    fn next_digit(&mut self) -> u8
+    {
+        // `remainder * 10` can overflow a `u128`, so it is added up one multiple at a
+        // time, which is safe as the running total always stays below `divisor`
+        let mut digit = 0;
+        let mut total: u128 = 0;
+
+        for _ in 0..10
+        {
+            if self.remainder >= self.divisor - total
+            {
+                digit += 1;
+                total = self.remainder - (self.divisor - total);
+            }
+            else
+            {
+                total += self.remainder;
+            }
+        }
+
+        self.remainder = total;
+
+        digit
+    }
+}
+
+/// Returns the magnitude of the numerator and the denominator as `u128`s, along with
+/// whether the fraction is negative. 
+fn unsigned_components<T: SignedInteger>(fraction: &GenericFraction<T>) -> (bool, u128, u128)
+{
+    let (numerator, denominator) = fraction.get_components();
+    let numerator: i128 = numerator.into();
+
+    (numerator < 0, numerator.unsigned_abs(), denominator.into())
+}
+
+/// Returns how many times `value` can be divided by `factor`. 
+fn multiplicity(mut value: u128, factor: u128) -> usize
+{
+    let mut count = 0;
+
+    while value.is_multiple_of(factor)
+    {
+        value /= factor;
+        count += 1;
+    }
+
+    count
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Returns the exact decimal expansion of the fraction, with the repeating digits
+    /// written in parentheses, in the same form that parsing accepts. 
+    /// 
+    /// The repeating part of a fraction can have up to one digit less than its
+    /// denominator, so the string can be very long for large prime denominators. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(1, 6).to_decimal_string(), "0.1(6)");
+    /// assert_eq!(Fraction::unchecked_from(-22, 7).to_decimal_string(), "-3.(142857)");
+    /// assert_eq!(Fraction::unchecked_from(5, 4).to_decimal_string(), "1.25");
+    /// assert_eq!(Fraction::from_i32(3).to_decimal_string(), "3");
+    /// ```
+    pub fn to_decimal_string(&self) -> String
+    {
+        let (negative, numerator, denominator) = unsigned_components(self);
+        let (integer, mut division) = LongDivision::new(numerator, denominator);
+
+        let mut result = if negative && numerator != 0 { format!("-{integer}") } else { integer.to_string() };
+
+        if division.remainder == 0
+        {
+            return result;
+        }
+
+        result.push('.');
+
+        // The digits stop repeating once every factor of 2 and 5 of the denominator
+        // has been used up, which is also where the repetend starts.
+        let non_repeating = multiplicity(denominator, 2).max(multiplicity(denominator, 5));
+
+        for _ in 0..non_repeating
+        {
+            result.push((b'0' + division.next_digit()) as char);
+        }
+
+        if division.remainder == 0
+        {
+            return result;
+        }
+
+        let repetend_start = division.remainder;
+
+        result.push('(');
+
+        loop
+        {
+            result.push((b'0' + division.next_digit()) as char);
+
+            if division.remainder == repetend_start
+            {
+                break;
+            }
+        }
+
+        result.push(')');
+
+        result
+    }
+
+    /// Returns the fraction as a decimal with exactly `places` digits after the decimal
+    /// point, rounded with `mode`. 
+    /// The sign is left out if the rounded value is 0. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, RoundingMode};
+    /// 
+    /// let fraction = Fraction::unchecked_from(-2, 3);
+    /// 
+    /// assert_eq!(fraction.to_decimal_string_rounded(3, RoundingMode::HalfEven), "-0.667");
+    /// assert_eq!(fraction.to_decimal_string_rounded(3, RoundingMode::TowardZero), "-0.666");
+    /// assert_eq!(fraction.to_decimal_string_rounded(0, RoundingMode::Ceil), "0");
+    /// 
+    /// let tie = Fraction::unchecked_from(5, 8);
+    /// 
+    /// assert_eq!(tie.to_decimal_string_rounded(2, RoundingMode::HalfEven), "0.62");
+    /// assert_eq!(tie.to_decimal_string_rounded(2, RoundingMode::HalfUp), "0.63");
+    /// ```
+    pub fn to_decimal_string_rounded(&self, places: usize, mode: RoundingMode) -> String
+    {
+        let (negative, numerator, denominator) = unsigned_components(self);
+        let (mut integer, mut division) = LongDivision::new(numerator, denominator);
+
+        let mut digits: Vec<u8> = (0..places).map(|_| division.next_digit()).collect();
+
+        let truncated_is_odd = digits.last().map_or(integer % 2 == 1, |digit| digit % 2 == 1);
+
+        if mode.rounds_away(negative, truncated_is_odd, division.remainder, denominator)
+        {
+            // carry the increment through any trailing 9s, into the integer part if needed
+            let carried = digits.iter_mut().rev().all(|digit|
+            {
+                *digit = (*digit + 1) % 10;
+                *digit == 0
+            });
+
+            if carried
+            {
+                integer += 1;
+            }
+        }
+
+        let is_zero = integer == 0 && digits.iter().all(|&digit| digit == 0);
+
+        let mut result = if negative && !is_zero { format!("-{integer}") } else { integer.to_string() };
+
+        if places > 0
+        {
+            result.push('.');
+            result.extend(digits.iter().map(|&digit| (b'0' + digit) as char));
+        }
+
+        result
+    }
+}
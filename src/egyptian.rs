@@ -0,0 +1,240 @@
+use crate::fraction::{signed_from_magnitude, ArithmeticError, GenericFraction};
+use crate::gcd::gcd;
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// The method used to split a fraction into distinct unit fractions. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EgyptianStrategy
+{
+    /// Repeatedly takes the largest unit fraction that fits, as described by Fibonacci and
+    /// Sylvester. 
+    /// This always gives few terms quickly, but the denominators can grow very fast. 
+    Greedy,
+    /// Splits `p/q` into `a/2^k + r/(q * 2^k)` where `2^k` is the smallest power of 2 that
+    /// is at least `q`, and then writes `a` and `r` in binary. 
+    /// The denominators are at most about `2 * q^2`, but there are more terms. 
+    BinaryRemainder,
+    /// Searches for an expansion with the fewest terms, with ties broken by the smallest
+    /// first denominator, then the second, and so on. 
+    /// The search can take a very long time when the shortest expansion is long. 
+    Shortest,
+}
+
+/// Returns the largest unit fractions that fit, in order, or `None` if a denominator is
+/// above `max_denominator`. 
+fn greedy(mut numerator: u128, mut denominator: u128, max_denominator: u128) -> Option<Vec<u128>>
+{
+    let mut units = Vec::new();
+
+    while numerator != 0
+    {
+        let unit = denominator.div_ceil(numerator);
+
+        if unit > max_denominator
+        {
+            return None;
+        }
+
+        units.push(unit);
+
+        // numerator / denominator - 1 / unit, simplified
+        numerator = numerator.checked_mul(unit)? - denominator;
+        denominator = denominator.checked_mul(unit)?;
+
+        let divisor = gcd(numerator, denominator);
+
+        (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    }
+
+    Some(units)
+}
+
+/// Returns the unit fractions of the binary remainder method from largest to smallest,
+/// or `None` if a denominator is above `max_denominator`. 
+fn binary_remainder(numerator: u128, denominator: u128, max_denominator: u128) -> Option<Vec<u128>>
+{
+    let exponent = denominator.checked_next_power_of_two().map_or(u128::BITS, |power| power.trailing_zeros());
+
+    // numerator * 2^exponent = quotient * denominator + remainder, done with a widening
+    // shift as the product can overflow
+    let (mut quotient, mut remainder) = (0u128, numerator);
+
+    for _ in 0..exponent
+    {
+        quotient <<= 1;
+
+        if remainder >= denominator - remainder
+        {
+            remainder -= denominator - remainder;
+            quotient |= 1;
+        }
+        else
+        {
+            remainder *= 2;
+        }
+    }
+
+    // quotient / 2^exponent and remainder / (denominator * 2^exponent) are both written in
+    // binary, and the first set of denominators are powers of 2 while the second are not
+    let mut units = Vec::new();
+
+    for bit in (0..exponent).rev()
+    {
+        if quotient >> bit & 1 == 1
+        {
+            units.push(1u128.checked_shl(exponent - bit)?);
+        }
+    }
+
+    for bit in (0..exponent).rev()
+    {
+        if remainder >> bit & 1 == 1
+        {
+            units.push(1u128.checked_shl(exponent - bit).and_then(|power| denominator.checked_mul(power))?);
+        }
+    }
+
+    units.iter().all(|&unit| unit <= max_denominator).then_some(units)
+}
+
+/// Searches for `terms` distinct unit fractions that add up to `numerator / denominator`,
+/// with denominators above `smallest` and at most `max_denominator`, appending them to `units`. 
+fn search(numerator: u128, denominator: u128, terms: u128, smallest: u128, max_denominator: u128, units: &mut Vec<u128>) -> bool
+{
+    if terms == 1
+    {
+        let found = numerator == 1 && denominator > smallest && denominator <= max_denominator;
+
+        if found
+        {
+            units.push(denominator);
+        }
+
+        return found;
+    }
+
+    // the next unit is the largest of the terms that are left, so it is at least 1 / terms
+    // of the value
+    let Some(lowest) = smallest.checked_add(1).map(|next| next.max(denominator.div_ceil(numerator))) else { return false };
+    let highest = (denominator.saturating_mul(terms) / numerator).min(max_denominator);
+
+    for unit in lowest..=highest
+    {
+        // Intermediate values that do not fit in a `u128` can only come up for `i128`
+        // fractions, and those branches are skipped.
+        let next = numerator.checked_mul(unit).zip(denominator.checked_mul(unit));
+
+        let Some((scaled_numerator, next_denominator)) = next else { continue };
+
+        let next_numerator = scaled_numerator - denominator;
+        let divisor = gcd(next_numerator, next_denominator);
+
+        units.push(unit);
+
+        if search(next_numerator / divisor, next_denominator / divisor, terms - 1, unit, max_denominator, units)
+        {
+            return true;
+        }
+
+        units.pop();
+    }
+
+    false
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Writes the fraction as a sum of distinct unit fractions, with the integer part
+    /// split off first as a whole number if it is not 0. 
+    /// The integer part is rounded down so that the unit fractions are always positive,
+    /// which means that `-3/4` is written as `-1 + 1/4`. 
+    /// 
+    /// Returns `ArithmeticError::DivByZero` if the denominator is 0, and
+    /// `ArithmeticError::Overflow` if a denominator can not be represented. 
+    /// `EgyptianStrategy::Shortest` only looks for expansions at most as long as the first
+    /// of the other two that fits, so it returns `ArithmeticError::Overflow` if neither does. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::egyptian::EgyptianStrategy;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let fraction = Fraction::unchecked_from(5, 121);
+    /// 
+    /// assert_eq!(fraction.to_egyptian(EgyptianStrategy::Greedy), Err(ArithmeticError::Overflow));
+    /// assert_eq!(fraction.to_egyptian(EgyptianStrategy::Shortest), Ok([25, 759, 208725].map(|d| Fraction::unchecked_from(1, d)).to_vec()));
+    /// 
+    /// let improper = Fraction::unchecked_from(11, 4);
+    /// 
+    /// assert_eq!(improper.to_egyptian(EgyptianStrategy::Greedy), Ok(vec![Fraction::from_i32(2), Fraction::unchecked_from(1, 2), Fraction::unchecked_from(1, 4)]));
+    /// assert_eq!(Fraction::unchecked_from(3, 7).to_egyptian(EgyptianStrategy::BinaryRemainder).unwrap().len(), 4);
+    /// ```
+    pub fn to_egyptian(self, strategy: EgyptianStrategy) -> Result<Vec<GenericFraction<T>>, ArithmeticError>
+    {
+        if self.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let (numerator, denominator) = self.simplify().get_components();
+
+        let negative = numerator < T::ZERO;
+        let (magnitude, denominator): (u128, u128) = (numerator.unsigned_abs().into(), denominator.into());
+
+        // split into floor + remainder / denominator, with the remainder in [0, denominator)
+        let (whole, remainder) = match magnitude % denominator
+        {
+            0 => (magnitude / denominator, 0),
+            remainder if negative => (magnitude / denominator + 1, denominator - remainder),
+            remainder => (magnitude / denominator, remainder),
+        };
+
+        let mut result = Vec::new();
+
+        if whole != 0
+        {
+            let whole = signed_from_magnitude(negative, whole).ok_or(ArithmeticError::Overflow)?;
+
+            result.push(GenericFraction::from_integer(whole));
+        }
+
+        if remainder == 0
+        {
+            return Ok(result);
+        }
+
+        let max_denominator = T::Unsigned::MAX.into();
+
+        let units = match strategy
+        {
+            EgyptianStrategy::Greedy => greedy(remainder, denominator, max_denominator),
+            EgyptianStrategy::BinaryRemainder => binary_remainder(remainder, denominator, max_denominator),
+            EgyptianStrategy::Shortest =>
+            {
+                let known = greedy(remainder, denominator, max_denominator)
+                    .or_else(|| binary_remainder(remainder, denominator, max_denominator));
+
+                known.map(|known|
+                {
+                    let mut units = Vec::new();
+
+                    (1..known.len() as u128)
+                        .find(|&terms| search(remainder, denominator, terms, 0, max_denominator, &mut units))
+                        .map_or(known, |_| units)
+                })
+            }
+        };
+
+        let units = units.ok_or(ArithmeticError::Overflow)?.into_iter().map(|unit|
+        {
+            T::Unsigned::try_from(unit).ok().map(|unit| GenericFraction::unchecked_from(T::ONE, unit))
+        });
+
+        for unit in units
+        {
+            result.push(unit.ok_or(ArithmeticError::Overflow)?);
+        }
+
+        Ok(result)
+    }
+}
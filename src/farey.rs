@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+
+use crate::big_integer::BigInt;
+use crate::fraction::{signed_from_magnitude, ArithmeticError, GenericFraction};
+use crate::integer::{SignedInteger, UnsignedInteger};
+use crate::stern_brocot::{Direction, SternBrocotPath};
+
+/// An iterator over the Farey sequence of a given order, which is every simplified
+/// fraction from 0 to 1 with a denominator of at most the order, in increasing order. 
+/// Each fraction is found from the previous two in constant time. 
+/// 
+/// ```
+/// use complex::farey::Farey;
+/// 
+/// let sequence: Vec<String> = Farey::<i32>::new(4).map(|fraction| fraction.to_string()).collect();
+/// 
+/// assert_eq!(sequence, ["0", "1/4", "1/3", "1/2", "2/3", "3/4", "1"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Farey<T: SignedInteger>
+{
+    range: FareyRange<T>,
+}
+
+impl<T: SignedInteger> Farey<T>
+{
+    /// Creates an iterator over the Farey sequence of order `order`. 
+    /// Fractions with a numerator that does not fit in `T` are only possible when
+    /// `order` is above `T::MAX`, in which case the iterator ends at the first of them. 
+    /// Panics if `order` is 0. 
+    pub fn new(order: T::Unsigned) -> Farey<T>
+    {
+        Farey {range: FareyRange::new(GenericFraction::from_integer(T::ZERO), GenericFraction::from_integer(T::ONE), order)}
+    }
+}
+
+impl<T: SignedInteger> Iterator for Farey<T>
+{
+    type Item = GenericFraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.range.next()
+    }
+}
+
+/// An iterator over every simplified fraction from `start` to `end` inclusive with a
+/// denominator of at most the order, in increasing order. 
+/// This is the Farey sequence extended past 0 and 1 by shifting it by integers. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// use complex::farey::FareyRange;
+/// 
+/// let start = Fraction::unchecked_from(-1, 2);
+/// let end = Fraction::unchecked_from(4, 5);
+/// 
+/// let range: Vec<String> = FareyRange::new(start, end, 3).map(|fraction| fraction.to_string()).collect();
+/// 
+/// assert_eq!(range, ["-1/2", "-1/3", "0", "1/3", "1/2", "2/3"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FareyRange<T: SignedInteger>
+{
+    order: u128,
+    end: GenericFraction<T>,
+    current: Option<GenericFraction<T>>,
+    following: Option<GenericFraction<T>>,
+}
+
+impl<T: SignedInteger> FareyRange<T>
+{
+    /// Creates an iterator over the fractions from `start` to `end` inclusive with a
+    /// denominator of at most `order`. 
+    /// The iterator ends early at the first fraction that does not fit in `GenericFraction<T>`. 
+    /// Panics if `order` is 0 or either bound has 0 for its denominator. 
+    pub fn new(start: GenericFraction<T>, end: GenericFraction<T>, order: T::Unsigned) -> FareyRange<T>
+    {
+        assert!(order != T::Unsigned::ZERO, "order should be at least 1");
+        assert!(start.get_denominator() != T::Unsigned::ZERO && end.get_denominator() != T::Unsigned::ZERO, "bounds should not have 0 for a denominator");
+
+        let successor = |fraction: GenericFraction<T>| fraction.farey_neighbors(order).ok().map(|(_, successor)| successor);
+
+        let start = start.simplify();
+        let current = if start.get_denominator() <= order { Some(start) } else { successor(start) };
+
+        FareyRange {order: order.into(), end, current, following: current.and_then(successor)}
+    }
+}
+
+impl<T: SignedInteger> Iterator for FareyRange<T>
+{
+    type Item = GenericFraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let current = self.current.filter(|current| *current <= self.end)?;
+
+        self.current = self.following;
+        self.following = self.following
+            .filter(|following| *following < self.end)
+            .and_then(|following| next_term(current, following, self.order));
+
+        Some(current)
+    }
+}
+
+/// Returns the term that comes after `current` in the Farey sequence of order `order`,
+/// given the term before it, or `None` if it does not fit. 
+fn next_term<T: SignedInteger>(previous: GenericFraction<T>, current: GenericFraction<T>, order: u128) -> Option<GenericFraction<T>>
+{
+    let (a, b): (i128, u128) = (previous.get_numerator().into(), previous.get_denominator().into());
+    let (c, d): (i128, u128) = (current.get_numerator().into(), current.get_denominator().into());
+
+    // The next term is (k * c - a) / (k * d - b), where k is (order + b) / d rounded down,
+    // which is worked out in parts to avoid overflowing.
+    let k = order / d + b / d + (order % d >= d - b % d) as u128;
+
+    let numerator = i128::try_from(k).ok()
+        .and_then(|k| k.checked_mul(c)?.checked_sub(a))
+        .or_else(|| (&(&BigInt::from(k) * &BigInt::from(c)) - &BigInt::from(a)).to_i128())?;
+
+    // the denominator is at most `order`, so wrapping gives the exact result
+    let denominator = k.wrapping_mul(d).wrapping_sub(b);
+
+    Some(GenericFraction::unchecked_from(T::try_from(numerator).ok()?, T::Unsigned::try_from(denominator).ok()?))
+}
+
+/// Returns the closest fractions below and above a positive fraction with a denominator of
+/// at most `order`, as magnitudes, or `None` if a numerator overflows. 
+fn positive_neighbors(path: &SternBrocotPath, order: u128) -> Option<((u128, u128), (u128, u128))>
+{
+    let add = |a: (u128, u128), steps: u128, b: (u128, u128)|
+    {
+        Some((b.0.checked_mul(steps)?.checked_add(a.0)?, b.1 * steps + a.1))
+    };
+
+    // Walk down the Stern–Brocot tree while the nodes are in the sequence, where the
+    // current node is the mediant of the bounds.
+    let (mut left, mut right) = ((0u128, 1u128), (1u128, 0u128));
+
+    for &(direction, length) in path.runs()
+    {
+        let room = order - (left.1 + right.1);
+
+        let steps = match direction
+        {
+            Direction::Left => length.min(room / left.1),
+            Direction::Right => length.min(room.checked_div(right.1).unwrap_or(u128::MAX)),
+        };
+
+        match direction
+        {
+            Direction::Left => right = add(right, steps, left)?,
+            Direction::Right => left = add(left, steps, right)?,
+        }
+
+        // the value is between the node and one of the bounds, with nothing else in between
+        if steps < length
+        {
+            let node = add(left, 1, right)?;
+
+            return Some(match direction
+            {
+                Direction::Left => (left, node),
+                Direction::Right => (node, right),
+            });
+        }
+    }
+
+    // The value is in the sequence, and its neighbors are the descendants of the bounds
+    // closest to it.
+    let node = add(left, 1, right)?;
+
+    Some((add(left, (order - left.1) / node.1, node)?, add(right, (order - right.1) / node.1, node)?))
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Returns the closest fractions below and above this one with a denominator of at
+    /// most `order`, which are its predecessor and successor in the Farey sequence of that
+    /// order, extended past 0 and 1. 
+    /// Returns `ArithmeticError::DivByZero` if the denominator is 0, and
+    /// `ArithmeticError::Overflow` if either neighbor can not be represented. 
+    /// Panics if `order` is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let fraction = Fraction::unchecked_from(2, 5);
+    /// 
+    /// assert_eq!(fraction.farey_neighbors(5), Ok((Fraction::unchecked_from(1, 3), Fraction::unchecked_from(1, 2))));
+    /// assert_eq!(fraction.farey_neighbors(4), Ok((Fraction::unchecked_from(1, 3), Fraction::unchecked_from(1, 2))));
+    /// assert_eq!(fraction.farey_neighbors(7), Ok((Fraction::unchecked_from(1, 3), Fraction::unchecked_from(3, 7))));
+    /// assert_eq!(Fraction::from_i32(-1).farey_neighbors(3), Ok((Fraction::unchecked_from(-4, 3), Fraction::unchecked_from(-2, 3))));
+    /// assert_eq!(Fraction::from_i32(i32::MAX).farey_neighbors(1), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn farey_neighbors(self, order: T::Unsigned) -> Result<(GenericFraction<T>, GenericFraction<T>), ArithmeticError>
+    {
+        assert!(order != T::Unsigned::ZERO, "order should be at least 1");
+
+        if self.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let (numerator, denominator) = self.get_components();
+        let order: u128 = order.into();
+
+        let (below, above) = if numerator == T::ZERO
+        {
+            ((1, order), (1, order))
+        }
+        else
+        {
+            let path = SternBrocotPath::from_magnitude(numerator.unsigned_abs().into(), denominator.into());
+
+            positive_neighbors(&path, order).ok_or(ArithmeticError::Overflow)?
+        };
+
+        let from_magnitude = |negative: bool, (numerator, denominator): (u128, u128)|
+        {
+            Some(GenericFraction::unchecked_from(signed_from_magnitude(negative, numerator)?, T::Unsigned::try_from(denominator).ok()?))
+        };
+
+        // the neighbors of a negative value are the neighbors of its magnitude, swapped and negated
+        let neighbors = match numerator.cmp(&T::ZERO)
+        {
+            Ordering::Less => from_magnitude(true, above).zip(from_magnitude(true, below)),
+            Ordering::Equal => from_magnitude(true, below).zip(from_magnitude(false, above)),
+            Ordering::Greater => from_magnitude(false, below).zip(from_magnitude(false, above)),
+        };
+
+        neighbors.ok_or(ArithmeticError::Overflow)
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::complex::GenericComplex;
+use crate::fraction::{ArithmeticError, GenericFraction};
+use crate::integer::SignedInteger;
+
+/// A number type with addition, subtraction and multiplication, such as `Fraction`,
+/// `Complex`, `f32` and `f64`. 
+/// The exact types panic on overflow, like their operators. 
+/// 
+/// ```
+/// use complex::{Complex, Fraction, Ring};
+/// 
+/// // evaluates a polynomial with the coefficients from the highest power down
+/// fn evaluate<R: Ring>(coefficients: &[R], x: R) -> R
+/// {
+///     coefficients.iter().fold(R::ZERO, |total, &coefficient| total * x + coefficient)
+/// }
+/// 
+/// let coefficients = [Fraction::from_i32(2), Fraction::from_i32(-3), Fraction::from_i32(1)];
+/// 
+/// assert_eq!(evaluate(&coefficients, Fraction::unchecked_from(1, 2)), Fraction::ZERO);
+/// assert_eq!(evaluate(&[Complex::ONE, Complex::ZERO, Complex::ONE], Complex::I), Complex::ZERO);
+/// assert_eq!(evaluate(&[2.0, -3.0, 1.0], 0.25), 0.375);
+/// ```
+pub trait Ring:
+    Copy + PartialEq + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign
+{
+    /// The additive identity. 
+    const ZERO: Self;
+
+    /// The multiplicative identity. 
+    const ONE: Self;
+}
+
+/// A `Ring` with division, where every value other than `ZERO` has a reciprocal. 
+/// 
+/// ```
+/// use complex::{Field, Fraction};
+/// 
+/// // solves `a * x + b = 0`
+/// fn root<F: Field>(a: F, b: F) -> Option<F>
+/// {
+///     Some(-b * a.reciprocal().ok()?)
+/// }
+/// 
+/// assert_eq!(root(Fraction::from_i32(3), Fraction::from_i32(2)), Some(Fraction::unchecked_from(-2, 3)));
+/// assert_eq!(root(Fraction::ZERO, Fraction::ONE), None);
+/// assert_eq!(root(4.0, 1.0), Some(-0.25));
+/// ```
+pub trait Field: Ring + Div<Output = Self> + DivAssign
+{
+    /// Returns `ONE / self`, or `ArithmeticError::DivByZero` if `self` is zero, including
+    /// for floats. 
+    /// The exact types return `ArithmeticError::Overflow` if the reciprocal can not be
+    /// represented. 
+    fn reciprocal(self) -> Result<Self, ArithmeticError>;
+}
+
+/// A `Field` with a total order that is compatible with its arithmetic, as for
+/// `Fraction` and the floats outside of NaN. 
+/// The sign of a value is found by comparing it with `ZERO`. 
+/// 
+/// ```
+/// use complex::{Fraction, OrderedField};
+/// 
+/// // returns the largest distance between two of the values
+/// fn spread<F: OrderedField>(values: &[F]) -> F
+/// {
+///     values.iter().flat_map(|&a| values.iter().map(move |&b| (a - b).magnitude()))
+///         .fold(F::ZERO, |largest, distance| if distance > largest { distance } else { largest })
+/// }
+/// 
+/// assert_eq!(spread(&[Fraction::unchecked_from(1, 2), Fraction::from_i32(-1), Fraction::ONE]), Fraction::from_i32(2));
+/// assert_eq!(spread(&[-0.5, 0.25]), 0.75);
+/// ```
+pub trait OrderedField: Field + PartialOrd
+{
+    /// Returns the absolute value, named so that it does not clash with `abs` from
+    /// `num_traits::Signed`. 
+    /// Panics if the absolute value can not be represented, such as for `T::MIN` over
+    /// an odd denominator. 
+    fn magnitude(self) -> Self;
+}
+
+/// A `Field` whose values have a real and an imaginary component from an ordered
+/// field, such as `Complex`, where `Fraction` and the floats are the case of an
+/// imaginary component that is always zero. 
+/// 
+/// ```
+/// use complex::{Complex, ComplexField, Fraction};
+/// 
+/// // returns the value with the largest magnitude, for use as a pivot
+/// fn largest<F: ComplexField>(values: &[F]) -> Option<F>
+/// {
+///     values.iter().copied().reduce(|a, b| if b.abs_squared() > a.abs_squared() { b } else { a })
+/// }
+/// 
+/// assert_eq!(largest(&[Complex::from_i32_pair(1, 1), Complex::from_i32(-2), Complex::I]), Some(Complex::from_i32(-2)));
+/// assert_eq!(largest(&[Fraction::unchecked_from(1, 2), Fraction::unchecked_from(-2, 3)]), Some(Fraction::unchecked_from(-2, 3)));
+/// assert_eq!(largest::<f64>(&[]), None);
+/// ```
+pub trait ComplexField: Field
+{
+    /// The ordered field of the components. 
+    type Real: OrderedField;
+
+    /// Creates a value from its real component. 
+    fn from_real(value: Self::Real) -> Self;
+
+    /// Returns the real component. 
+    fn real(self) -> Self::Real;
+
+    /// Returns the imaginary component. 
+    fn imaginary(self) -> Self::Real;
+
+    /// Returns the complex conjugate, which is the value itself for real fields. 
+    fn conjugate(self) -> Self;
+
+    /// Returns the squared magnitude, `self * self.conjugate()` as a real value. 
+    fn abs_squared(self) -> Self::Real;
+}
+
+impl<T: SignedInteger> Ring for GenericFraction<T>
+{
+    const ZERO: Self = GenericFraction::ZERO;
+    const ONE: Self = GenericFraction::ONE;
+}
+
+impl<T: SignedInteger> Field for GenericFraction<T>
+{
+    fn reciprocal(self) -> Result<Self, ArithmeticError>
+    {
+        GenericFraction::ONE.checked_div(self)
+    }
+}
+
+impl<T: SignedInteger> OrderedField for GenericFraction<T>
+{
+    fn magnitude(self) -> Self
+    {
+        GenericFraction::abs(self)
+    }
+}
+
+impl<T: SignedInteger> ComplexField for GenericFraction<T>
+{
+    type Real = GenericFraction<T>;
+
+    fn from_real(value: Self::Real) -> Self
+    {
+        value
+    }
+
+    fn real(self) -> Self::Real
+    {
+        self
+    }
+
+    fn imaginary(self) -> Self::Real
+    {
+        GenericFraction::ZERO
+    }
+
+    fn conjugate(self) -> Self
+    {
+        self
+    }
+
+    fn abs_squared(self) -> Self::Real
+    {
+        self * self
+    }
+}
+
+impl<T: SignedInteger> Ring for GenericComplex<GenericFraction<T>>
+{
+    const ZERO: Self = GenericComplex::ZERO;
+    const ONE: Self = GenericComplex::ONE;
+}
+
+impl<T: SignedInteger> Field for GenericComplex<GenericFraction<T>>
+{
+    fn reciprocal(self) -> Result<Self, ArithmeticError>
+    {
+        GenericComplex::ONE.checked_div(self)
+    }
+}
+
+impl<T: SignedInteger> ComplexField for GenericComplex<GenericFraction<T>>
+{
+    type Real = GenericFraction<T>;
+
+    fn from_real(value: Self::Real) -> Self
+    {
+        GenericComplex::from(value, GenericFraction::ZERO)
+    }
+
+    fn real(self) -> Self::Real
+    {
+        self.get_real()
+    }
+
+    fn imaginary(self) -> Self::Real
+    {
+        self.get_imaginary()
+    }
+
+    fn conjugate(self) -> Self
+    {
+        Self::conjugate(self)
+    }
+
+    fn abs_squared(self) -> Self::Real
+    {
+        Self::abs_squared(self)
+    }
+}
+
+/// Implements the field traits for a primitive float. 
+macro_rules! float_field
+{
+    ($($float:ty),*) =>
+    {
+        $(
+            impl Ring for $float
+            {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+            }
+
+            impl Field for $float
+            {
+                fn reciprocal(self) -> Result<Self, ArithmeticError>
+                {
+                    if self == 0.0 { Err(ArithmeticError::DivByZero) } else { Ok(self.recip()) }
+                }
+            }
+
+            impl OrderedField for $float
+            {
+                fn magnitude(self) -> Self
+                {
+                    <$float>::abs(self)
+                }
+            }
+
+            impl ComplexField for $float
+            {
+                type Real = $float;
+
+                fn from_real(value: Self::Real) -> Self
+                {
+                    value
+                }
+
+                fn real(self) -> Self::Real
+                {
+                    self
+                }
+
+                fn imaginary(self) -> Self::Real
+                {
+                    0.0
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self
+                }
+
+                fn abs_squared(self) -> Self::Real
+                {
+                    self * self
+                }
+            }
+        )*
+    };
+}
+
+float_field!(f32, f64);
@@ -0,0 +1,272 @@
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::fraction::{signed_from_magnitude, FloatConversionError, GenericFraction};
+use crate::integer::SignedInteger;
+
+/// Splits a float into its sign, an odd mantissa (or 0) and an exponent, so that its
+/// value is exactly `mantissa * 2^exponent`. 
+fn decompose(value: f64) -> Result<(bool, u64, i32), FloatConversionError>
+{
+    if value.is_nan()
+    {
+        return Err(FloatConversionError::NotANumber);
+    }
+
+    if value.is_infinite()
+    {
+        return Err(FloatConversionError::Infinite);
+    }
+
+    let bits = value.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+
+    // subnormal numbers have no implicit leading 1, but share the smallest exponent
+    let (mantissa, exponent) = if biased_exponent == 0
+    {
+        (fraction, -1074)
+    }
+    else
+    {
+        (fraction | 1 << 52, biased_exponent - 1075)
+    };
+
+    if mantissa == 0
+    {
+        return Ok((negative, 0, 0));
+    }
+
+    let zeros = mantissa.trailing_zeros();
+
+    Ok((negative, mantissa >> zeros, exponent + zeros as i32))
+}
+
+/// Returns the number of significant bits in `value`. 
+fn bit_length(value: u128) -> i32
+{
+    (u128::BITS - value.leading_zeros()) as i32
+}
+
+/// Returns `2^exponent`, which must be between -1022 and 1023 so it is a normal `f64`. 
+fn power_of_two(exponent: i32) -> f64
+{
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+/// Rounds `numerator / denominator` to the nearest float with `precision` significant bits,
+/// with ties to even, where bits below `2^min_exponent` are rounded off like they are for
+/// subnormal numbers. 
+/// The result is returned as an `f64`, which holds it exactly for both `f32` and `f64`. 
+pub(crate) fn round_quotient(numerator: u128, denominator: u128, precision: u32, min_exponent: i32) -> f64
+{
+    if numerator == 0
+    {
+        return 0.0;
+    }
+
+    // Scaling by 2^shift gives a quotient with precision + 2 or precision + 3 bits, which
+    // leaves a guard bit to round with, and `sticky` records if anything below it is set.
+    let shift = precision as i32 + 2 - (bit_length(numerator) - bit_length(denominator));
+
+    let (quotient, sticky) = if shift >= 0
+    {
+        let mut quotient = numerator / denominator;
+        let mut remainder = numerator % denominator;
+
+        for _ in 0..shift
+        {
+            // doubling the remainder can overflow, but it is always below `denominator`
+            let bit = remainder >= denominator - remainder;
+
+            remainder = if bit { remainder - (denominator - remainder) } else { remainder * 2 };
+            quotient = quotient << 1 | bit as u128;
+        }
+
+        (quotient, remainder != 0)
+    }
+    else
+    {
+        let integer = numerator / denominator;
+        let mask = (1 << -shift) - 1;
+
+        (integer >> -shift, !numerator.is_multiple_of(denominator) || integer & mask != 0)
+    };
+
+    let mut dropped = bit_length(quotient) - precision as i32;
+    let mut exponent = dropped - shift;
+
+    if exponent < min_exponent
+    {
+        dropped += min_exponent - exponent;
+        exponent = min_exponent;
+    }
+
+    let half = 1 << (dropped - 1);
+    let rest = quotient & ((1 << dropped) - 1);
+    let mut mantissa = quotient >> dropped;
+
+    if rest > half || rest == half && (sticky || mantissa & 1 == 1)
+    {
+        mantissa += 1;
+    }
+
+    // fractions are never further than 2^±128 from 1, so the power of 2 is always normal
+    mantissa as f64 * power_of_two(exponent)
+}
+
+impl<T: SignedInteger> TryFrom<f64> for GenericFraction<T>
+{
+    type Error = FloatConversionError;
+
+    /// Converts a float to the fraction with exactly the same value, which always has a
+    /// power of 2 as its denominator. 
+    /// Returns an error for NaN, infinity, or if the numerator or denominator does not fit. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, GenericFraction};
+    /// use complex::fraction::FloatConversionError;
+    /// 
+    /// assert_eq!(Fraction::try_from(-0.375), Ok(Fraction::unchecked_from(-3, 8)));
+    /// assert_eq!(Fraction::try_from(0.1), Err(FloatConversionError::OutOfRange));
+    /// assert_eq!(GenericFraction::<i64>::try_from(0.1), Ok(GenericFraction::unchecked_from(3602879701896397, 36028797018963968)));
+    /// assert_eq!(Fraction::try_from(f64::NAN), Err(FloatConversionError::NotANumber));
+    /// ```
+    fn try_from(value: f64) -> Result<Self, Self::Error>
+    {
+        let (negative, mantissa, exponent) = decompose(value)?;
+
+        let (numerator, denominator) = if exponent >= 0
+        {
+            let numerator = (mantissa as u128).checked_shl(exponent as u32)
+                .filter(|numerator| numerator >> exponent == mantissa as u128)
+                .ok_or(FloatConversionError::OutOfRange)?;
+
+            (numerator, 1)
+        }
+        else
+        {
+            let denominator = 1u128.checked_shl(exponent.unsigned_abs()).ok_or(FloatConversionError::OutOfRange)?;
+
+            (mantissa as u128, denominator)
+        };
+
+        let numerator = signed_from_magnitude(negative, numerator).ok_or(FloatConversionError::OutOfRange)?;
+        let denominator = T::Unsigned::try_from(denominator).map_err(|_| FloatConversionError::OutOfRange)?;
+
+        Ok(GenericFraction::unchecked_from(numerator, denominator))
+    }
+}
+
+impl<T: SignedInteger> TryFrom<f32> for GenericFraction<T>
+{
+    type Error = FloatConversionError;
+
+    /// Converts a float to the fraction with exactly the same value, see `TryFrom<f64>`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::try_from(1.5f32), Ok(Fraction::unchecked_from(3, 2)));
+    /// ```
+    fn try_from(value: f32) -> Result<Self, Self::Error>
+    {
+        // every f32 is exactly representable as an f64
+        GenericFraction::try_from(value as f64)
+    }
+}
+
+impl TryFrom<f64> for BigFraction
+{
+    type Error = FloatConversionError;
+
+    /// Converts a float to the fraction with exactly the same value, returning an error
+    /// for NaN and infinity. 
+    /// 
+    /// ```
+    /// use complex::BigFraction;
+    /// 
+    /// let fraction = BigFraction::try_from(1e30).unwrap();
+    /// 
+    /// assert_eq!(fraction.to_string(), "1000000000000000019884624838656");
+    /// ```
+    fn try_from(value: f64) -> Result<Self, Self::Error>
+    {
+        let (negative, mantissa, exponent) = decompose(value)?;
+
+        let power = BigUint::from(2u32).pow(exponent.unsigned_abs());
+        let mantissa = BigUint::from(mantissa);
+
+        let (numerator, denominator) = if exponent >= 0 { (&mantissa * &power, BigUint::one()) } else { (mantissa, power) };
+
+        Ok(BigFraction::from(BigInt::from_biguint(negative, numerator), denominator).expect("Denominator is not 0"))
+    }
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Returns the value of the fraction as the nearest `f32`, with ties rounded to even. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let fraction = Fraction::unchecked_from(1, 3);
+    /// 
+    /// assert_eq!(fraction.to_f32(), 1.0 / 3.0);
+    /// assert_eq!(Fraction::unchecked_from(16777217, 1).to_f32(), 16777216.0);
+    /// ```
+    pub fn to_f32(&self) -> f32
+    {
+        let (numerator, denominator) = self.get_components();
+        let negative = numerator < T::ZERO;
+        let (numerator, denominator): (u128, u128) = (numerator.unsigned_abs().into(), denominator.into());
+
+        // f32 division of values that are converted exactly is already correctly rounded
+        let magnitude = if (numerator < 1 << f32::MANTISSA_DIGITS && denominator < 1 << f32::MANTISSA_DIGITS) || denominator == 0
+        {
+            numerator as f32 / denominator as f32
+        }
+        else
+        {
+            round_quotient(numerator, denominator, f32::MANTISSA_DIGITS, -149) as f32
+        };
+
+        if negative { -magnitude } else { magnitude }
+    }
+
+    /// Returns the fraction closest to `value` with a denominator of at most `max_denominator`. 
+    /// Unlike `from_f64`, this always terminates, and returns `None` for NaN, infinity,
+    /// values that are out of range, or if `max_denominator` is 0. 
+    /// The result is the best rational approximation, which can be a semiconvergent of
+    /// the continued fraction rather than a convergent, as for `311/99` below. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::approximate_f64(0.33333, 100), Some(Fraction::unchecked_from(1, 3)));
+    /// assert_eq!(Fraction::approximate_f64(std::f64::consts::PI, 1000), Some(Fraction::unchecked_from(355, 113)));
+    /// assert_eq!(Fraction::approximate_f64(std::f64::consts::PI, 100), Some(Fraction::unchecked_from(311, 99)));
+    /// assert_eq!(Fraction::approximate_f64(-2.4, 1), Some(Fraction::from_i32(-2)));
+    /// assert_eq!(Fraction::approximate_f64(f64::INFINITY, 100), None);
+    /// assert_eq!(Fraction::approximate_f64(1e10, 100), None);
+    /// ```
+    pub fn approximate_f64(value: f64, max_denominator: T::Unsigned) -> Option<GenericFraction<T>>
+    {
+        GenericFraction::from_big_limited(&BigFraction::try_from(value).ok()?, max_denominator)
+    }
+
+    /// Returns the fraction closest to `value` with a denominator of at most `max_denominator`,
+    /// the same as `approximate_f64`, for symmetry with `limit_denominator`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::best_approximation(0.1, 10), Some(Fraction::unchecked_from(1, 10)));
+    /// assert_eq!(Fraction::best_approximation(std::f64::consts::PI, 100), Some(Fraction::unchecked_from(311, 99)));
+    /// assert_eq!(Fraction::best_approximation(f64::NAN, 10), None);
+    /// ```
+    pub fn best_approximation(value: f64, max_denominator: T::Unsigned) -> Option<GenericFraction<T>>
+    {
+        GenericFraction::approximate_f64(value, max_denominator)
+    }
+}
@@ -1,19 +1,105 @@
-use crate::Complex;
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::complex::GenericComplex;
+use crate::float::round_quotient;
+use crate::gcd::gcd;
+use crate::integer::{SignedInteger, UnsignedInteger};
+use crate::parse::{parse_fraction, parse_fraction_radix};
+use crate::rounding::{round_big, RoundingMode};
 
-/// Represents a rational number through a fraction, storing the numerator as an `i32`, 
-/// and the denominator as a `u32`, for consistency with mathematical standards. 
+/// Represents a rational number through a fraction, storing the numerator as a signed
+/// integer `T`, and the denominator as the unsigned integer of the same width, for
+/// consistency with mathematical standards. 
+/// 
+/// Methods that need arithmetic on `T`, such as `from`, `simplify` and `abs`, can not
+/// be `const fn`, since trait methods can not be called in a constant context. 
+/// Constants can be created with `unchecked_from`, `from_integer` or `Fraction::from_i32`. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// 
+/// const HALF: Fraction = Fraction::unchecked_from(1, 2);
+/// const TWO: Fraction = Fraction::from_i32(2);
+/// 
+/// assert_eq!(HALF * TWO, Fraction::ONE);
+/// ```
 #[derive(Clone, Copy, Debug)]
-pub struct Fraction
+pub struct GenericFraction<T: SignedInteger>
 {
-    numerator: i32,
-    denominator: u32,
+    numerator: T,
+    denominator: T::Unsigned,
 }
 
+/// A fraction with an `i32` numerator and a `u32` denominator. 
+pub type Fraction = GenericFraction<i32>;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct DivByZeroError;
 
-impl Fraction
+/// The error returned by the checked arithmetic methods of `GenericFraction` and `GenericComplex`. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticError
+{
+    /// A value was divided by 0, or a fraction with 0 for its denominator was used. 
+    DivByZero,
+    /// The exact result can not be represented with the integer type of the fraction. 
+    Overflow,
+}
+
+impl From<DivByZeroError> for ArithmeticError
+{
+    fn from(_: DivByZeroError) -> Self
+    {
+        ArithmeticError::DivByZero
+    }
+}
+
+/// The error returned when converting a float to a fraction fails. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatConversionError
+{
+    /// The float was NaN. 
+    NotANumber,
+    /// The float was positive or negative infinity. 
+    Infinite,
+    /// The numerator or denominator of the exact value does not fit in the integer type
+    /// of the fraction. 
+    OutOfRange,
+}
+
+impl std::fmt::Display for FloatConversionError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            FloatConversionError::NotANumber => write!(f, "cannot convert NaN to a fraction"),
+            FloatConversionError::Infinite => write!(f, "cannot convert infinity to a fraction"),
+            FloatConversionError::OutOfRange => write!(f, "float is out of range for the fraction type"),
+        }
+    }
+}
+
+impl std::fmt::Display for ArithmeticError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ArithmeticError::DivByZero => write!(f, "attempt to divide by zero"),
+            ArithmeticError::Overflow => write!(f, "arithmetic operation overflowed"),
+        }
+    }
+}
+
+impl<T: SignedInteger> GenericFraction<T>
 {
+    /// The fraction `0/1`. 
+    pub const ZERO: GenericFraction<T> = GenericFraction::from_integer(T::ZERO);
+
+    /// The fraction `1/1`. 
+    pub const ONE: GenericFraction<T> = GenericFraction::from_integer(T::ONE);
+
     /// Creates a fraction that is fully simplified. 
     /// Will return `DivByZeroError` if denominator is 0. 
     /// ```
@@ -24,9 +110,9 @@ impl Fraction
     /// assert_eq!(simplified.get_components(), Fraction::from(1, 2).unwrap().get_components());
     /// 
     /// ```
-    pub const fn from(numerator: i32, denominator: u32) -> Result<Fraction, DivByZeroError>
+    pub fn from(numerator: T, denominator: T::Unsigned) -> Result<GenericFraction<T>, DivByZeroError>
     {
-        let fraction = Fraction::unsimplified_from(numerator, denominator);
+        let fraction = GenericFraction::unsimplified_from(numerator, denominator);
 
         match fraction
         {
@@ -45,14 +131,14 @@ impl Fraction
     /// 
     /// assert_ne!(unsimplified.get_components(), Fraction::unsimplified_from(1, 2).unwrap().get_components());
     /// ```
-    pub const fn unsimplified_from(numerator: i32, denominator: u32) -> Result<Fraction, DivByZeroError>
+    pub fn unsimplified_from(numerator: T, denominator: T::Unsigned) -> Result<GenericFraction<T>, DivByZeroError>
     {
-        if denominator == 0
+        if denominator == T::Unsigned::ZERO
         {
             return Err(DivByZeroError);
         }
 
-        Ok(Fraction {numerator, denominator})
+        Ok(GenericFraction {numerator, denominator})
     }
 
     /// Creates a fraction with no checks on the input. 
@@ -68,9 +154,9 @@ impl Fraction
     /// 
     /// assert_eq!(invalid.get_denominator(), 0);
     /// ```
-    pub const fn unchecked_from(numerator: i32, denominator: u32) -> Fraction
+    pub const fn unchecked_from(numerator: T, denominator: T::Unsigned) -> GenericFraction<T>
     {
-        Fraction {numerator, denominator}
+        GenericFraction {numerator, denominator}
     }
 
     /// Simplifies a fraction by dividing both the numerator and the denominator
@@ -87,30 +173,48 @@ impl Fraction
     /// 
     /// assert_eq!(simplified.get_components(), also_simplified.get_components());
     /// ```
-    pub const fn simplify(&self) -> Fraction
+    pub fn simplify(&self) -> GenericFraction<T>
     {
-        let gcd = gcd(self.numerator.abs() as u32, self.denominator);
+        let gcd = gcd(self.numerator.unsigned_abs(), self.denominator);
 
-        let numerator = self.numerator / gcd as i32;
+        let numerator = divide_by_unsigned(self.numerator, gcd);
         let denominator = self.denominator / gcd;
         
-        Fraction {numerator, denominator}
+        GenericFraction {numerator, denominator}
+    }
+
+    /// Returns whether the fraction is in its canonical form, which is simplified with a
+    /// denominator that is not 0. 
+    /// This is the form returned by `Fraction::from` and by the arithmetic operations,
+    /// but `unchecked_from` and `unsimplified_from` can create fractions that are not. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert!(Fraction::unchecked_from(-1, 2).is_canonical());
+    /// assert!(!Fraction::unchecked_from(2, 4).is_canonical());
+    /// assert!(!Fraction::unchecked_from(0, 3).is_canonical());
+    /// assert!(!Fraction::unchecked_from(1, 0).is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool
+    {
+        self.denominator != T::Unsigned::ZERO && gcd(self.numerator.unsigned_abs(), self.denominator) == T::Unsigned::ONE
     }
     
     /// Creates a fraction with `value` as the numerator and 1 as the denominator. 
     /// The returned fraction will represent the same number as `value`. 
     /// 
     /// ```
-    /// use complex::Fraction;
+    /// use complex::GenericFraction;
     /// 
-    /// let a = Fraction::from_i32(2);
-    /// let b = Fraction::unchecked_from(2, 1);
+    /// let a = GenericFraction::<i64>::from_integer(2);
+    /// let b = GenericFraction::<i64>::unchecked_from(2, 1);
     /// 
     /// assert_eq!(a, b);
     /// ```
-    pub const fn from_i32(value: i32) -> Fraction
+    pub const fn from_integer(value: T) -> GenericFraction<T>
     {
-        Fraction::unchecked_from(value, 1)
+        GenericFraction::unchecked_from(value, T::Unsigned::ONE)
     }
     
     /// Returns a tuple with the numerator for the first value, and the denominator
@@ -137,7 +241,7 @@ impl Fraction
     /// assert_eq!(a.get_components(), b.get_components());
     /// assert_ne!(a.get_components(), c.get_components());
     /// ```
-    pub const fn get_components(&self) -> (i32, u32)
+    pub const fn get_components(&self) -> (T, T::Unsigned)
     {
         (self.numerator, self.denominator)
     }
@@ -151,7 +255,7 @@ impl Fraction
     /// 
     /// assert_eq!(fraction.get_numerator(), 1);
     /// ```
-    pub const fn get_numerator(&self) -> i32
+    pub const fn get_numerator(&self) -> T
     {
         self.numerator
     }
@@ -165,74 +269,94 @@ impl Fraction
     /// 
     /// assert_eq!(fraction.get_denominator(), 2);
     /// ```
-    pub const fn get_denominator(&self) -> u32
+    pub const fn get_denominator(&self) -> T::Unsigned
     {
         self.denominator
     }
 
-    /// Returns the numerator divided by the denominator, as an `f64`. 
+    /// Returns the numerator divided by the denominator as the nearest `f64`, with ties
+    /// rounded to even. 
     /// 
     /// ```
-    /// use complex::Fraction;
+    /// use complex::{Fraction, GenericFraction};
     /// 
     /// let fraction = Fraction::unchecked_from(1, 3);
     /// let float_value = fraction.to_f64();
     /// 
     /// assert_eq!(float_value, 1.0 / 3.0);
+    /// 
+    /// // the numerator is rounded to 9007199254742988.0 when converted on its own
+    /// let large = GenericFraction::<i64>::unchecked_from(9007199254742987, 3);
+    /// 
+    /// assert_eq!(large.to_f64(), 3002399751580995.5);
     /// ```
     pub fn to_f64(&self) -> f64
     {
-        (self.numerator as f64) / (self.denominator as f64)
+        let negative = self.numerator < T::ZERO;
+        let (numerator, denominator): (u128, u128) = (self.numerator.unsigned_abs().into(), self.denominator.into());
+
+        // f64 division of values that are converted exactly is already correctly rounded
+        let magnitude = if (numerator < 1 << f64::MANTISSA_DIGITS && denominator < 1 << f64::MANTISSA_DIGITS) || denominator == 0
+        {
+            numerator as f64 / denominator as f64
+        }
+        else
+        {
+            round_quotient(numerator, denominator, f64::MANTISSA_DIGITS, -1074)
+        };
+
+        if negative { -magnitude } else { magnitude }
     }
 
     /// Finds the closest fractional value to `value`, with a tolerance of
     /// `error`. 
     /// 
+    /// Does not terminate for NaN or infinity, and does not check that the
+    /// result is in range. 
+    /// 
     /// ```
+    /// #![allow(deprecated)]
     /// use complex::Fraction;
     /// 
     /// let fraction = Fraction::from_f64(0.33333, 0.00001);
     /// 
     /// assert_eq!(fraction, Fraction::unchecked_from(1, 3));
     /// ```
-    pub fn from_f64(value: f64, error: f64) -> Fraction
+    #[deprecated(note = "can loop forever, use `approximate_f64` or `TryFrom<f64>` instead")]
+    pub fn from_f64(value: f64, error: f64) -> GenericFraction<T>
     {
         let integer_part = value.floor();
         let decimal_part = value - integer_part;
 
         if decimal_part < error
         {
-            return Fraction::from_i32(integer_part as i32);
+            return GenericFraction::from_integer(T::from_f64(integer_part));
         }
         else if decimal_part > 1.0 - error
         {
-            return Fraction::from_i32(integer_part as i32 + 1);
+            return GenericFraction::from_integer(T::from_f64(integer_part) + T::ONE);
         }
 
-        let mut lower = Fraction::from_i32(0);
-        let mut upper = Fraction::from_i32(1);
+        let mut lower = GenericFraction::from_integer(T::ZERO);
+        let mut upper = GenericFraction::from_integer(T::ONE);
 
         loop
         {
-            let middle = Fraction::unchecked_from
-            (
-                lower.numerator + upper.numerator,
-                lower.denominator + upper.denominator
-            );
+            let middle = lower.mediant(upper).unwrap_or_else(|error| panic!("{error}"));
 
-            if (middle.numerator as f64) > middle.denominator as f64 * (decimal_part + error)
+            if middle.numerator.to_f64() > middle.denominator.to_f64() * (decimal_part + error)
             {
                 upper = middle;
             }
-            else if (middle.numerator as f64) < middle.denominator as f64 * (decimal_part - error)
+            else if middle.numerator.to_f64() < middle.denominator.to_f64() * (decimal_part - error)
             {
                 lower = middle;
             }
             else
             {
-                return Fraction::from
+                return GenericFraction::from
                 (
-                    integer_part as i32 * middle.denominator as i32 + middle.numerator,
+                    T::from_f64(integer_part) * T::from_unsigned(middle.denominator) + middle.numerator,
                     middle.denominator
                 )
                 .expect("Denominator will not be 0");
@@ -241,42 +365,231 @@ impl Fraction
     }
 }
 
-impl std::fmt::Display for Fraction
+impl Fraction
+{
+    /// Creates a fraction with `value` as the numerator and 1 as the denominator. 
+    /// The returned fraction will represent the same number as `value`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(2);
+    /// let b = Fraction::unchecked_from(2, 1);
+    /// 
+    /// assert_eq!(a, b);
+    /// ```
+    pub const fn from_i32(value: i32) -> Fraction
+    {
+        Fraction::unchecked_from(value, 1)
+    }
+}
+
+impl<T: SignedInteger> std::fmt::Display for GenericFraction<T>
 {
+    /// Writes the fraction as `n/d`, or as an integer if the denominator is 1. 
+    /// A precision writes the value as a decimal instead, rounded with
+    /// `RoundingMode::HalfEven`, and the alternate flag writes it as a mixed number. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let fraction = Fraction::unchecked_from(-3, 2);
+    /// 
+    /// assert_eq!(format!("{fraction}"), "-3/2");
+    /// assert_eq!(format!("{fraction:.3}"), "-1.500");
+    /// assert_eq!(format!("{fraction:#}"), "-1 1/2");
+    /// assert_eq!(format!("{:.5}", Fraction::unchecked_from(2, 3)), "0.66667");
+    /// assert_eq!(format!("{:>10.2}", Fraction::unchecked_from(1, 3)), "      0.33");
+    /// assert_eq!(format!("[{fraction:^8}]"), "[  -3/2  ]");
+    /// assert_eq!(format!("{:+#}", Fraction::unchecked_from(5, 4)), "+1 1/4");
+    /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        if self.denominator != 1
+        let text = self.unpadded_string(f.precision(), f.alternate());
+
+        // `pad` would cut the text to the precision, while `pad_integral` only applies
+        // the width, fill, alignment and sign flags, as for the integer types
+        match text.strip_prefix('-')
+        {
+            Some(magnitude) => f.pad_integral(false, "", magnitude),
+            None => f.pad_integral(true, "", &text),
+        }
+    }
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Returns the text that `Display` writes before it is padded to the width. 
+    fn unpadded_string(&self, precision: Option<usize>, alternate: bool) -> String
+    {
+        if self.denominator != T::Unsigned::ZERO
+        {
+            if let Some(places) = precision
+            {
+                return self.to_decimal_string_rounded(places, RoundingMode::HalfEven);
+            }
+
+            let whole = self.numerator.unsigned_abs() / self.denominator;
+
+            if alternate && whole != T::Unsigned::ZERO && self.denominator != T::Unsigned::ONE
+            {
+                let sign = if self.numerator < T::ZERO { "-" } else { "" };
+                let remainder = self.numerator.unsigned_abs() % self.denominator;
+
+                return format!("{sign}{whole} {remainder}/{}", self.denominator);
+            }
+        }
+
+        if self.denominator != T::Unsigned::ONE
         {
-            write!(f, "{}/{}", self.numerator, self.denominator)
+            format!("{}/{}", self.numerator, self.denominator)
         }
         else
         {
-            write!(f, "{}", self.numerator)
+            self.numerator.to_string()
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseFractionError;
+/// The kind of error that made a string fail to parse as a fraction. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFractionErrorKind
+{
+    /// The string was empty, or only contained whitespace. 
+    Empty,
+    /// A character was found that is not valid at its position. 
+    InvalidCharacter,
+    /// The string ended before the number was complete, as in `"1/"` or `"0.(3"`. 
+    UnexpectedEnd,
+    /// The denominator of the fraction was 0. 
+    ZeroDenominator,
+    /// The number can not be represented with the integer type of the fraction, or its
+    /// exponent is too large, and the position is where the number starts. 
+    Overflow,
+}
+
+/// The error returned when parsing a fraction fails, storing what went wrong and
+/// the byte position in the string where it happened. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFractionError
+{
+    kind: ParseFractionErrorKind,
+    position: usize,
+}
+
+impl ParseFractionError
+{
+    pub(crate) fn new(kind: ParseFractionErrorKind, position: usize) -> ParseFractionError
+    {
+        ParseFractionError {kind, position}
+    }
+
+    /// Returns the kind of error that occurred. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ParseFractionErrorKind;
+    /// 
+    /// let error = "1/0".parse::<Fraction>().unwrap_err();
+    /// 
+    /// assert_eq!(error.kind(), ParseFractionErrorKind::ZeroDenominator);
+    /// ```
+    pub fn kind(&self) -> ParseFractionErrorKind
+    {
+        self.kind
+    }
+
+    /// Returns the byte position in the string where the error occurred. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let error = "1.5x".parse::<Fraction>().unwrap_err();
+    /// 
+    /// assert_eq!(error.position(), 3);
+    /// ```
+    pub fn position(&self) -> usize
+    {
+        self.position
+    }
+}
+
+impl std::fmt::Display for ParseFractionError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let description = match self.kind
+        {
+            ParseFractionErrorKind::Empty => "cannot parse fraction from empty string",
+            ParseFractionErrorKind::InvalidCharacter => "invalid character in fraction",
+            ParseFractionErrorKind::UnexpectedEnd => "fraction ended unexpectedly",
+            ParseFractionErrorKind::ZeroDenominator => "fraction has 0 for its denominator",
+            ParseFractionErrorKind::Overflow => "fraction is too large to fit in target type",
+        };
+
+        write!(f, "{description} at byte {}", self.position)
+    }
+}
 
-impl std::str::FromStr for Fraction
+impl<T: SignedInteger> std::str::FromStr for GenericFraction<T>
 {
     type Err = ParseFractionError;
 
+    /// Parses a fraction written as `a/b`, an integer, a mixed number such as `1 1/2`,
+    /// or a decimal number, which may use scientific notation and may end with a repetend
+    /// in parentheses or marked with overlines. 
+    /// Every decimal is converted to the exact fraction it represents. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!("3 / 4".parse(), Ok(Fraction::unchecked_from(3, 4)));
+    /// assert_eq!("3".parse(), Ok(Fraction::from_i32(3)));
+    /// assert_eq!("0.75".parse(), Ok(Fraction::unchecked_from(3, 4)));
+    /// assert_eq!("-1 1/2".parse(), Ok(Fraction::unchecked_from(-3, 2)));
+    /// assert_eq!("-2.5e-3".parse(), Ok(Fraction::unchecked_from(-1, 400)));
+    /// assert_eq!("0.(3)".parse(), Ok(Fraction::unchecked_from(1, 3)));
+    /// assert_eq!("0.16\u{305}".parse(), Ok(Fraction::unchecked_from(1, 6)));
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err>
     {
-        let (numerator_str, denominator_str) = s
-            .split_once('/')
-            .ok_or(ParseFractionError)?;
+        // any exponent that is more than 40 beyond the length of the string makes the
+        // numerator or the denominator larger than an i128
+        let value = parse_fraction(s, 40)?;
+
+        // like the exponent limit, overflow is reported where the number starts
+        GenericFraction::try_from(value)
+            .map_err(|_| ParseFractionError::new(ParseFractionErrorKind::Overflow, s.len() - s.trim_start().len()))
+    }
+}
 
-        let numerator = numerator_str.trim().parse::<i32>().map_err(|_| ParseFractionError)?;
-        let denominator = denominator_str.trim().parse::<u32>().map_err(|_| ParseFractionError)?;
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Parses an integer or a fraction written as `a/b` with digits in base `radix`,
+    /// without any whitespace, in the same way as `i32::from_str_radix`. 
+    /// The result is simplified. 
+    /// 
+    /// Panics if `radix` is not in the range from 2 to 36. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ParseFractionErrorKind;
+    /// 
+    /// assert_eq!(Fraction::from_str_radix("-ff/10", 16), Ok(Fraction::unchecked_from(-255, 16)));
+    /// assert_eq!(Fraction::from_str_radix("110/100", 2), Ok(Fraction::unchecked_from(3, 2)));
+    /// assert_eq!(Fraction::from_str_radix("12/3", 2).unwrap_err().kind(), ParseFractionErrorKind::InvalidCharacter);
+    /// assert_eq!(Fraction::from_str_radix("z/0", 36).unwrap_err().position(), 2);
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<GenericFraction<T>, ParseFractionError>
+    {
+        assert!((2..=36).contains(&radix), "radix should be in the range from 2 to 36");
 
-        Fraction::from(numerator, denominator).map_err(|_| ParseFractionError)
+        GenericFraction::try_from(parse_fraction_radix(s, radix)?)
+            .map_err(|_| ParseFractionError::new(ParseFractionErrorKind::Overflow, 0))
     }
 }
 
-impl PartialEq for Fraction
+impl<T: SignedInteger> PartialEq for GenericFraction<T>
 {
     fn eq(&self, other: &Self) -> bool
     {
@@ -284,9 +597,46 @@ impl PartialEq for Fraction
     }
 }
 
-impl Eq for Fraction {}
+impl<T: SignedInteger> Eq for GenericFraction<T> {}
+
+impl<T: SignedInteger> std::hash::Hash for GenericFraction<T>
+{
+    /// Hashes the simplified form of the fraction, so that fractions that are equal
+    /// have the same hash, however they were created. 
+    /// 
+    /// ```
+    /// use std::collections::HashSet;
+    /// 
+    /// use complex::Fraction;
+    /// 
+    /// let set: HashSet<Fraction> = [Fraction::unchecked_from(1, 2), Fraction::unchecked_from(-3, 6), Fraction::unchecked_from(2, 4)].into();
+    /// 
+    /// assert_eq!(set.len(), 2);
+    /// assert!(set.contains(&Fraction::unchecked_from(-4, 8)));
+    /// ```
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H)
+    {
+        // fractions with 0 for their denominator are only equal to themselves
+        let simplified = if self.denominator == T::Unsigned::ZERO { *self } else { self.simplify() };
+
+        let numerator: i128 = simplified.numerator.into();
+        let denominator: u128 = simplified.denominator.into();
+
+        numerator.hash(state);
+        denominator.hash(state);
+    }
+}
+
+impl<T: SignedInteger> Default for GenericFraction<T>
+{
+    /// Returns `GenericFraction::ZERO`. 
+    fn default() -> Self
+    {
+        GenericFraction::ZERO
+    }
+}
 
-impl PartialOrd for Fraction
+impl<T: SignedInteger> PartialOrd for GenericFraction<T>
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
     {
@@ -294,32 +644,97 @@ impl PartialOrd for Fraction
     }
 }
 
-impl Ord for Fraction
+impl<T: SignedInteger> Ord for GenericFraction<T>
 {
+    /// Compares the values of two fractions exactly, without multiplying any of their
+    /// components, so it can not overflow. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(i32::MAX, u32::MAX);
+    /// let b = Fraction::unchecked_from(i32::MAX - 1, u32::MAX - 2);
+    /// 
+    /// assert!(a > b);
+    /// assert_eq!(a.max(b), a);
+    /// assert_eq!(Fraction::from_i32(3).clamp(b, a), a);
+    /// ```
     fn cmp(&self, other: &Self) -> std::cmp::Ordering
     {
-        (self.numerator * other.denominator as i32).cmp(&(other.numerator * self.denominator as i32))
+        if self.denominator == other.denominator
+        {
+            return self.numerator.cmp(&other.numerator);
+        }
+
+        let self_negative = self.numerator < T::ZERO;
+        let other_negative = other.numerator < T::ZERO;
+
+        if self_negative != other_negative
+        {
+            return self_negative.cmp(&other_negative).reverse();
+        }
+
+        let ordering = compare_magnitudes
+        (
+            (self.numerator.unsigned_abs(), self.denominator),
+            (other.numerator.unsigned_abs(), other.denominator)
+        );
+
+        if self_negative { ordering.reverse() } else { ordering }
     }
 }
 
-impl std::ops::Add<Fraction> for Fraction
+/// Compares two non-negative fractions by comparing the terms of their continued
+/// fraction expansions one at a time. 
+/// A fraction with 0 for its denominator is treated as being larger than any other value. 
+fn compare_magnitudes<U: UnsignedInteger>(mut a: (U, U), mut b: (U, U)) -> std::cmp::Ordering
 {
-    type Output = Fraction;
+    use std::cmp::Ordering;
+
+    // the ordering of the current terms is reversed each time the reciprocals are taken
+    let mut reversed = false;
 
-    fn add(self, rhs: Fraction) -> Self::Output
+    loop
     {
-        let denominator_gcd = gcd(self.denominator, rhs.denominator);
+        let ordering = match (a.1 == U::ZERO, b.1 == U::ZERO)
+        {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) =>
+            {
+                let ordering = (a.0 / a.1).cmp(&(b.0 / b.1));
+
+                if ordering == Ordering::Equal
+                {
+                    // both fractional parts are now compared by comparing their reciprocals
+                    a = (a.1, a.0 % a.1);
+                    b = (b.1, b.0 % b.1);
+
+                    reversed = !reversed;
+
+                    continue;
+                }
+
+                ordering
+            }
+        };
 
-        let numerator = self.numerator * (rhs.denominator / denominator_gcd) as i32
-            + rhs.numerator * (self.denominator / denominator_gcd) as i32;
+        return if reversed { ordering.reverse() } else { ordering };
+    }
+}
 
-        let denominator = self.denominator * rhs.denominator / denominator_gcd;
+impl<T: SignedInteger> std::ops::Add<GenericFraction<T>> for GenericFraction<T>
+{
+    type Output = GenericFraction<T>;
 
-        Fraction::from(numerator, denominator).expect("Fraction should not have 0 for denominator")
+    fn add(self, rhs: GenericFraction<T>) -> Self::Output
+    {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::AddAssign for Fraction
+impl<T: SignedInteger> std::ops::AddAssign for GenericFraction<T>
 {
     fn add_assign(&mut self, rhs: Self)
     {
@@ -327,27 +742,27 @@ impl std::ops::AddAssign for Fraction
     }
 }
 
-impl std::ops::Neg for Fraction
+impl<T: SignedInteger> std::ops::Neg for GenericFraction<T>
 {
-    type Output = Fraction;
+    type Output = GenericFraction<T>;
 
     fn neg(self) -> Self::Output
     {
-        Fraction::unchecked_from(-self.numerator, self.denominator)
+        self.checked_neg().unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::Sub<Fraction> for Fraction
+impl<T: SignedInteger> std::ops::Sub<GenericFraction<T>> for GenericFraction<T>
 {
-    type Output = Fraction;
+    type Output = GenericFraction<T>;
 
-    fn sub(self, rhs: Fraction) -> Self::Output
+    fn sub(self, rhs: GenericFraction<T>) -> Self::Output
     {
-        self + (-rhs)
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::SubAssign for Fraction
+impl<T: SignedInteger> std::ops::SubAssign for GenericFraction<T>
 {
     fn sub_assign(&mut self, rhs: Self)
     {
@@ -355,18 +770,17 @@ impl std::ops::SubAssign for Fraction
     }
 }
 
-impl std::ops::Mul<Fraction> for Fraction
+impl<T: SignedInteger> std::ops::Mul<GenericFraction<T>> for GenericFraction<T>
 {
-    type Output = Fraction;
+    type Output = GenericFraction<T>;
 
-    fn mul(self, rhs: Fraction) -> Self::Output
+    fn mul(self, rhs: GenericFraction<T>) -> Self::Output
     {
-        Fraction::from(self.numerator * rhs.numerator, self.denominator * rhs.denominator)
-            .expect("Fraction should not have 0 for denominator")
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
-impl std::ops::MulAssign for Fraction
+impl<T: SignedInteger> std::ops::MulAssign for GenericFraction<T>
 {
     fn mul_assign(&mut self, rhs: Self)
     {
@@ -374,7 +788,7 @@ impl std::ops::MulAssign for Fraction
     }
 }
 
-impl Fraction
+impl<T: SignedInteger> GenericFraction<T>
 {
     /// Returns a number representing the sign of the fraction. 
     /// - `0` if the number is 0
@@ -388,14 +802,18 @@ impl Fraction
     /// 
     /// assert_eq!(fraction.signum(), -1);
     /// ```
-    pub const fn signum(self) -> i32
+    pub fn signum(self) -> T
     {
         self.numerator.signum()
     }
 
     /// Returns a fraction with the numerator and denominator of `self` switched,
-    /// perserving the sign of the numerator, returning a `DivByZeroError` if the
-    /// denominator is zero. 
+    /// perserving the sign of the numerator and simplified, returning a `DivByZeroError`
+    /// if the numerator is zero. 
+    /// 
+    /// Panics if the reciprocal overflows, which happens when the simplified denominator
+    /// is above `T::MAX`. 
+    /// `ONE.checked_div(self)` returns an `ArithmeticError` instead. 
     /// 
     /// ```
     /// use complex::Fraction;
@@ -403,92 +821,1253 @@ impl Fraction
     /// let fraction = Fraction::unchecked_from(1, 2);
     /// 
     /// assert_eq!(fraction.reciprocal().unwrap(), Fraction::unchecked_from(2, 1));
+    /// assert_eq!(Fraction::unchecked_from(-4, 6).reciprocal().unwrap(), Fraction::unchecked_from(-3, 2));
+    /// assert!(Fraction::ZERO.reciprocal().is_err());
     /// ```
-    pub const fn reciprocal(self) -> Result<Fraction, DivByZeroError>
+    pub fn reciprocal(self) -> Result<GenericFraction<T>, DivByZeroError>
     {
-        Fraction::unsimplified_from(self.denominator as i32 * self.numerator.signum(), self.numerator.abs() as u32)
+        match GenericFraction::ONE.checked_div(self)
+        {
+            Err(ArithmeticError::DivByZero) => Err(DivByZeroError),
+            result => Ok(result.unwrap_or_else(|error| panic!("{error}"))),
+        }
     }
 
     /// Returns the absolute value of the fraction. 
     /// 
+    /// Panics if the result can not be represented, like `Neg`. 
+    /// `checked_abs` returns an `ArithmeticError` instead. 
+    /// 
     /// ```
     /// use complex::Fraction;
     /// 
     /// let fraction = Fraction::unchecked_from(-1, 2);
     /// 
     /// assert_eq!(fraction.abs(), Fraction::unchecked_from(1, 2));
+    /// assert_eq!(Fraction::unchecked_from(i32::MIN, 2).abs(), Fraction::from_i32(1 << 30));
+    /// ```
+    pub fn abs(self) -> GenericFraction<T>
+    {
+        if self.numerator < T::ZERO { -self } else { self }
+    }
+
+    /// Returns the square root of the fraction, which is imaginary for negative values. 
+    /// The result is exact if the numerator and denominator are both perfect squares,
+    /// and is otherwise approximated through `to_f64` and `approximate_f64`. 
+    /// 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let a = Fraction::unchecked_from(4, 9);
+    /// let b = Fraction::unchecked_from(-1, 4);
+    /// 
+    /// assert_eq!(a.sqrt(), Complex::from_fraction(Fraction::unchecked_from(2, 3)));
+    /// assert_eq!(b.sqrt(), Complex::from_fraction_imaginary(Fraction::unchecked_from(1, 2)));
     /// ```
-    pub const fn abs(self) -> Fraction
+    pub fn sqrt(self) -> GenericComplex<GenericFraction<T>>
     {
-        Fraction::unchecked_from(self.numerator.abs(), self.denominator)
+        self.checked_sqrt().unwrap_or_else(|error| panic!("{error}"))
     }
 
-    pub fn sqrt(self) -> Complex
+    /// Returns the square root of the fraction like `sqrt`, or `ArithmeticError::DivByZero`
+    /// if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let root = Fraction::from_i32(i32::MIN).checked_sqrt().unwrap();
+    /// 
+    /// assert_eq!(root.get_real(), Fraction::ZERO);
+    /// assert!((root.get_imaginary().to_f64() - 2f64.powf(15.5)).abs() < 1e-4);
+    /// assert_eq!(Fraction::unchecked_from(1, 0).checked_sqrt(), Err(ArithmeticError::DivByZero));
+    /// ```
+    pub fn checked_sqrt(self) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
     {
-        let value = Fraction::from_f64(self.abs().to_f64().sqrt(), 0.000000000001);
+        if self.denominator == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let negative = self.numerator < T::ZERO;
+
+        // the magnitude of `T::MIN` can not be negated, but it is never a perfect square,
+        // so only the approximation is needed, which takes the magnitude as an `f64`
+        let exact = if negative { self.checked_neg().ok().and_then(GenericFraction::try_exact_sqrt) } else { self.try_exact_sqrt() };
 
-        if self.numerator < 0
+        let value = match exact
         {
-            Complex::from_fraction_imaginary(Fraction::from_i32(0))
+            Some(value) => value,
+            None => GenericFraction::approximate_f64(self.to_f64().abs().sqrt(), T::Unsigned::MAX).ok_or(ArithmeticError::Overflow)?,
+        };
+
+        if negative
+        {
+            Ok(GenericComplex::from(GenericFraction::ZERO, value))
         }
         else
         {
-            Complex::from_fraction(value)
+            Ok(GenericComplex::from(value, GenericFraction::ZERO))
         }
     }
-}
-
-impl std::ops::Div<Fraction> for Fraction
-{
-    type Output = Fraction;
 
-    fn div(self, rhs: Fraction) -> Self::Output
+    /// Returns the exact square root of the fraction, or `None` if it is negative or
+    /// has no rational square root. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(4, 9).try_exact_sqrt(), Some(Fraction::unchecked_from(2, 3)));
+    /// assert_eq!(Fraction::unchecked_from(8, 18).try_exact_sqrt(), Some(Fraction::unchecked_from(2, 3)));
+    /// assert_eq!(Fraction::unchecked_from(2, 1).try_exact_sqrt(), None);
+    /// assert_eq!(Fraction::unchecked_from(-4, 9).try_exact_sqrt(), None);
+    /// ```
+    pub fn try_exact_sqrt(self) -> Option<GenericFraction<T>>
     {
-        self * rhs.reciprocal().expect("Divide by 0")
+        self.nth_root(2)
     }
-}
 
-impl std::ops::DivAssign for Fraction
-{
-    fn div_assign(&mut self, rhs: Self)
+    /// Returns the exact `n`th root of the fraction, or `None` if it has no rational
+    /// `n`th root. 
+    /// Negative values only have roots when `n` is odd, and there is no 0th root. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-27, 8).nth_root(3), Some(Fraction::unchecked_from(-3, 2)));
+    /// assert_eq!(Fraction::unchecked_from(1, 16).nth_root(4), Some(Fraction::unchecked_from(1, 2)));
+    /// assert_eq!(Fraction::unchecked_from(-1, 16).nth_root(4), None);
+    /// assert_eq!(Fraction::unchecked_from(3, 1).nth_root(3), None);
+    /// ```
+    pub fn nth_root(self, n: u32) -> Option<GenericFraction<T>>
     {
-        *self = *self / rhs;
-    }
-}
+        if n == 0 || self.denominator == T::Unsigned::ZERO
+        {
+            return None;
+        }
 
-/// computes the greatest common divisor between the two numbers
-const fn gcd(a: u32, b: u32) -> u32
-{
-    let (mut small, mut large) = get_ordering(a, b);
+        let value = self.simplify();
 
-    let mut i = 1;
-    let mut result = 1;
+        if n == 1
+        {
+            return Some(value);
+        }
 
-    while i <= small
-    {
-        if small % i == 0 && large % i == 0
+        let negative = value.numerator < T::ZERO;
+
+        if negative && n.is_multiple_of(2)
         {
-            small /= i;
-            large /= i;
-            result *= i;
-            i = 1;
+            return None;
         }
 
-        i += 1;
+        let numerator = signed_from_unsigned::<T>(exact_integer_root(value.numerator.unsigned_abs(), n)?)?;
+        let denominator = exact_integer_root(value.denominator, n)?;
+
+        Some(GenericFraction::unchecked_from(if negative { -numerator } else { numerator }, denominator))
+    }
+
+    /// Raises the fraction to the power of `exponent` by repeated squaring, taking the
+    /// reciprocal for negative exponents. 
+    /// 
+    /// Panics if the result overflows, or if 0 is raised to a negative power. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let fraction = Fraction::unchecked_from(2, 3);
+    /// 
+    /// assert_eq!(fraction.pow(3), Fraction::unchecked_from(8, 27));
+    /// assert_eq!(fraction.pow(-2), Fraction::unchecked_from(9, 4));
+    /// assert_eq!(fraction.pow(0), Fraction::from_i32(1));
+    /// ```
+    pub fn pow(self, exponent: i32) -> GenericFraction<T>
+    {
+        self.checked_pow(exponent).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Raises the fraction to a rational power, by taking the root for the denominator
+    /// of `exponent` and then raising it to the power of the numerator. 
+    /// Returns `None` if the result is not rational, or can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let fraction = Fraction::unchecked_from(8, 27);
+    /// 
+    /// assert_eq!(fraction.pow_frac(Fraction::unchecked_from(2, 3)), Some(Fraction::unchecked_from(4, 9)));
+    /// assert_eq!(fraction.pow_frac(Fraction::unchecked_from(-1, 3)), Some(Fraction::unchecked_from(3, 2)));
+    /// assert_eq!(fraction.pow_frac(Fraction::unchecked_from(1, 2)), None);
+    /// ```
+    pub fn pow_frac(self, exponent: GenericFraction<T>) -> Option<GenericFraction<T>>
+    {
+        let exponent = GenericFraction::<T>::from(exponent.numerator, exponent.denominator).ok()?;
+
+        let power = i32::try_from(exponent.numerator.into()).ok()?;
+        let root = u32::try_from(exponent.denominator.into()).ok()?;
+
+        self.nth_root(root)?.checked_pow(power).ok()
+    }
+
+    /// Returns the fraction closest to `self` with a denominator of at most `max_denominator`,
+    /// choosing the one with the smaller denominator if two are equally close. 
+    /// 
+    /// Panics if `max_denominator` is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let fraction = Fraction::unchecked_from(314159, 100000);
+    /// 
+    /// assert_eq!(fraction.limit_denominator(10), Fraction::unchecked_from(22, 7));
+    /// assert_eq!(fraction.limit_denominator(1000), Fraction::unchecked_from(355, 113));
+    /// assert_eq!(Fraction::unchecked_from(-1, 3).limit_denominator(5), Fraction::unchecked_from(-1, 3));
+    /// ```
+    pub fn limit_denominator(self, max_denominator: T::Unsigned) -> GenericFraction<T>
+    {
+        assert!(max_denominator != T::Unsigned::ZERO, "max_denominator should be at least 1");
+
+        if self.denominator <= max_denominator
+        {
+            return self;
+        }
+
+        GenericFraction::from_big_limited(&self.into(), max_denominator).expect("Fraction is in range")
+    }
+
+    /// Returns the mediant `(a + c) / (b + d)` of `a/b` and `c/d`, which lies between the two
+    /// fractions, simplified. 
+    /// The result depends on the components as they are stored, so unsimplified fractions
+    /// can give a different mediant than the same values simplified. 
+    /// Denominators of 0 are allowed, so that `1/0` can be used as an upper bound like in
+    /// the Stern–Brocot tree. 
+    /// Returns `ArithmeticError::Overflow` if either sum can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(1, 2);
+    /// let b = Fraction::unchecked_from(2, 3);
+    /// 
+    /// assert_eq!(a.mediant(b), Ok(Fraction::unchecked_from(3, 5)));
+    /// assert_eq!(Fraction::from_i32(0).mediant(Fraction::unchecked_from(1, 0)), Ok(Fraction::from_i32(1)));
+    /// assert_eq!(Fraction::from_i32(i32::MAX).mediant(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn mediant(self, other: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        let numerator = self.numerator.checked_add(other.numerator).ok_or(ArithmeticError::Overflow)?;
+        let denominator = self.denominator.checked_add(other.denominator).ok_or(ArithmeticError::Overflow)?;
+
+        if denominator == T::Unsigned::ZERO
+        {
+            return Ok(GenericFraction::unchecked_from(numerator, denominator));
+        }
+
+        Ok(GenericFraction::unchecked_from(numerator, denominator).simplify())
+    }
+
+    /// Returns the integer part of the fraction, rounding towards zero. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-11, 4).trunc(), Fraction::from_i32(-2));
+    /// ```
+    pub fn trunc(self) -> GenericFraction<T>
+    {
+        self.round_integer(RoundingMode::TowardZero)
+    }
+
+    /// Returns the fractional part of the fraction, which has the same sign as the
+    /// fraction, so that `trunc() + fract()` is always the original value. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-11, 4).fract(), Fraction::unchecked_from(-3, 4));
+    /// ```
+    pub fn fract(self) -> GenericFraction<T>
+    {
+        // the remainder is at most the magnitude of the numerator, so it always fits
+        let remainder = (self.numerator.unsigned_abs() % self.denominator).into();
+        let remainder = signed_from_magnitude(self.numerator < T::ZERO, remainder).expect("Remainder is in range");
+
+        GenericFraction::unchecked_from(remainder, self.denominator).simplify()
+    }
+
+    /// Returns the largest integer less than or equal to the fraction. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-11, 4).floor(), Fraction::from_i32(-3));
+    /// assert_eq!(Fraction::unchecked_from(11, 4).floor(), Fraction::from_i32(2));
+    /// ```
+    pub fn floor(self) -> GenericFraction<T>
+    {
+        self.round_integer(RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to the fraction. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-11, 4).ceil(), Fraction::from_i32(-2));
+    /// assert_eq!(Fraction::unchecked_from(11, 4).ceil(), Fraction::from_i32(3));
+    /// ```
+    pub fn ceil(self) -> GenericFraction<T>
+    {
+        self.round_integer(RoundingMode::Ceil)
+    }
+
+    /// Returns the nearest integer to the fraction, with ties rounded away from zero
+    /// like `f64::round`. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(5, 2).round(), Fraction::from_i32(3));
+    /// assert_eq!(Fraction::unchecked_from(-5, 2).round(), Fraction::from_i32(-3));
+    /// assert_eq!(Fraction::unchecked_from(7, 3).round(), Fraction::from_i32(2));
+    /// ```
+    pub fn round(self) -> GenericFraction<T>
+    {
+        self.round_integer(RoundingMode::HalfUp)
+    }
+
+    /// Returns the nearest integer to the fraction, with ties rounded to the even
+    /// integer like `f64::round_ties_even`. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(5, 2).round_ties_even(), Fraction::from_i32(2));
+    /// assert_eq!(Fraction::unchecked_from(-7, 2).round_ties_even(), Fraction::from_i32(-4));
+    /// ```
+    pub fn round_ties_even(self) -> GenericFraction<T>
+    {
+        self.round_integer(RoundingMode::HalfEven)
+    }
+
+    /// Rounds the fraction to an integer with `mode`, which always fits as the
+    /// magnitude is at most half of the numerator when the denominator is above 1. 
+    fn round_integer(self, mode: RoundingMode) -> GenericFraction<T>
+    {
+        let negative = self.numerator < T::ZERO;
+        let (magnitude, denominator): (u128, u128) = (self.numerator.unsigned_abs().into(), self.denominator.into());
+
+        let (mut whole, remainder) = (magnitude / denominator, magnitude % denominator);
+
+        if mode.rounds_away(negative, whole % 2 == 1, remainder, denominator)
+        {
+            whole += 1;
+        }
+
+        GenericFraction::from_integer(signed_from_magnitude(negative, whole).expect("Rounded value is in range"))
+    }
+
+    /// Returns the integer `q` for which `self - q * rhs` is between 0 and `rhs.abs()`,
+    /// so that `q` is `self / rhs` rounded down for positive `rhs` and up for negative `rhs`,
+    /// like `f64::div_euclid`. 
+    /// Panics if `rhs` is 0 or the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(-7, 2);
+    /// 
+    /// assert_eq!(a.div_euclid(Fraction::from_i32(2)), -2);
+    /// assert_eq!(a.div_euclid(Fraction::from_i32(-2)), 2);
+    /// assert_eq!(Fraction::unchecked_from(7, 2).div_euclid(Fraction::unchecked_from(2, 3)), 5);
+    /// ```
+    pub fn div_euclid(self, rhs: GenericFraction<T>) -> T
+    {
+        self.integer_quotient(rhs, GenericFraction::euclid_mode(rhs)).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns the least non-negative remainder of `self` divided by `rhs`, which is
+    /// `self - rhs * self.div_euclid(rhs)`, like `f64::rem_euclid`. 
+    /// Panics if `rhs` is 0 or the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(-7, 2);
+    /// 
+    /// assert_eq!(a.rem_euclid(Fraction::from_i32(2)), Fraction::unchecked_from(1, 2));
+    /// assert_eq!(a.rem_euclid(Fraction::from_i32(-2)), Fraction::unchecked_from(1, 2));
+    /// assert_eq!(Fraction::unchecked_from(7, 2).rem_euclid(Fraction::unchecked_from(2, 3)), Fraction::unchecked_from(1, 6));
+    /// ```
+    pub fn rem_euclid(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.integer_remainder(rhs, GenericFraction::euclid_mode(rhs)).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns `self / rhs` rounded down to an integer. 
+    /// Panics if `rhs` is 0 or the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(-7, 2);
+    /// 
+    /// assert_eq!(a.div_floor(Fraction::from_i32(2)), -2);
+    /// assert_eq!(a.div_floor(Fraction::from_i32(-2)), 1);
+    /// ```
+    pub fn div_floor(self, rhs: GenericFraction<T>) -> T
+    {
+        self.integer_quotient(rhs, RoundingMode::Floor).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns `self - rhs * self.div_floor(rhs)`, which has the same sign as `rhs`,
+    /// like the modulo operator of Python. 
+    /// Panics if `rhs` is 0 or the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(-7, 2);
+    /// 
+    /// assert_eq!(a.mod_floor(Fraction::from_i32(2)), Fraction::unchecked_from(1, 2));
+    /// assert_eq!(a.mod_floor(Fraction::from_i32(-2)), Fraction::unchecked_from(-3, 2));
+    /// ```
+    pub fn mod_floor(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.integer_remainder(rhs, RoundingMode::Floor).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns `self / rhs` rounded towards zero along with the remainder `self % rhs`,
+    /// which has the same sign as `self` like the `%` operator on integers. 
+    /// Panics if `rhs` is 0 or either result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(-7, 2);
+    /// 
+    /// assert_eq!(a.div_rem(Fraction::from_i32(2)), (-1, Fraction::unchecked_from(-3, 2)));
+    /// assert_eq!(a.div_rem(Fraction::unchecked_from(-2, 3)), (5, Fraction::unchecked_from(-1, 6)));
+    /// ```
+    pub fn div_rem(self, rhs: GenericFraction<T>) -> (T, GenericFraction<T>)
+    {
+        let result = self.integer_quotient(rhs, RoundingMode::TowardZero)
+            .and_then(|quotient| Ok((quotient, self.integer_remainder(rhs, RoundingMode::TowardZero)?)));
+
+        result.unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Rounds the quotient down for positive `rhs` and up for negative `rhs`, so that the
+    /// remainder is never negative. 
+    fn euclid_mode(rhs: GenericFraction<T>) -> RoundingMode
+    {
+        if rhs.numerator < T::ZERO { RoundingMode::Ceil } else { RoundingMode::Floor }
+    }
+
+    /// Divides `self` by `rhs` and rounds the quotient to an integer with `mode`, using
+    /// only the backing integer type unless an intermediate value overflows. 
+    fn integer_quotient(self, rhs: GenericFraction<T>, mode: RoundingMode) -> Result<T, ArithmeticError>
+    {
+        GenericFraction::check_divisor(self, rhs)?;
+
+        match GenericFraction::div_fast(self, rhs)
+        {
+            Some(quotient) => Ok(quotient.round_integer(mode).numerator),
+            None => integer_from_big(self.big_quotient(rhs, mode)),
+        }
+    }
+
+    /// Returns `self - rhs * quotient` for the quotient rounded with `mode`, using only
+    /// the backing integer type unless an intermediate value overflows. 
+    fn integer_remainder(self, rhs: GenericFraction<T>, mode: RoundingMode) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        GenericFraction::check_divisor(self, rhs)?;
+
+        let fast = ||
+        {
+            let quotient = GenericFraction::div_fast(self, rhs)?.round_integer(mode);
+
+            GenericFraction::sub_fast(self, GenericFraction::mul_fast(rhs, quotient)?)
+        };
+
+        if let Some(remainder) = fast()
+        {
+            return Ok(remainder);
+        }
+
+        // the quotient can be too large to represent even when the remainder is not
+        let (value, divisor): (BigFraction, BigFraction) = (self.into(), rhs.into());
+        let remainder = &value - &(&divisor * &BigFraction::from_integer(self.big_quotient(rhs, mode)));
+
+        GenericFraction::try_from(remainder).map_err(|_| ArithmeticError::Overflow)
+    }
+
+    /// Returns `ArithmeticError::DivByZero` if `rhs` is 0 or either denominator is 0. 
+    fn check_divisor(self, rhs: GenericFraction<T>) -> Result<(), ArithmeticError>
+    {
+        if rhs.numerator == T::ZERO || self.denominator == T::Unsigned::ZERO || rhs.denominator == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        Ok(())
+    }
+
+    /// Divides `self` by `rhs` exactly and rounds the quotient to an integer with `mode`,
+    /// which can be representable even when `self / rhs` is not. 
+    fn big_quotient(self, rhs: GenericFraction<T>, mode: RoundingMode) -> BigInt
+    {
+        let (value, divisor): (BigFraction, BigFraction) = (self.into(), rhs.into());
+
+        round_big(&(&value / &divisor), mode)
+    }
+}
+
+impl<T: SignedInteger> std::ops::Div<GenericFraction<T>> for GenericFraction<T>
+{
+    type Output = GenericFraction<T>;
+
+    fn div(self, rhs: GenericFraction<T>) -> Self::Output
+    {
+        self.checked_div(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::ops::DivAssign for GenericFraction<T>
+{
+    fn div_assign(&mut self, rhs: Self)
+    {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: SignedInteger> std::ops::Rem<GenericFraction<T>> for GenericFraction<T>
+{
+    type Output = GenericFraction<T>;
+
+    /// Returns the remainder of truncated division, which has the same sign as `self`
+    /// like the `%` operator on integers, see `checked_rem`. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(7, 2) % Fraction::from_i32(2), Fraction::unchecked_from(3, 2));
+    /// assert_eq!(Fraction::unchecked_from(-7, 2) % Fraction::from_i32(2), Fraction::unchecked_from(-3, 2));
+    /// assert_eq!(Fraction::unchecked_from(7, 2) % Fraction::from_i32(-2), Fraction::unchecked_from(3, 2));
+    /// ```
+    fn rem(self, rhs: GenericFraction<T>) -> Self::Output
+    {
+        self.checked_rem(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: SignedInteger> std::ops::RemAssign for GenericFraction<T>
+{
+    fn rem_assign(&mut self, rhs: Self)
+    {
+        *self = *self % rhs;
+    }
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Adds two fractions, returning `ArithmeticError::Overflow` if the simplified
+    /// result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(1, 2);
+    /// let b = Fraction::unchecked_from(1, 3);
+    /// 
+    /// assert_eq!(a.checked_add(b), Ok(Fraction::unchecked_from(5, 6)));
+    /// assert_eq!(Fraction::from_i32(i32::MAX).checked_add(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_add(self, rhs: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericFraction::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, returning `ArithmeticError::Overflow` if the
+    /// simplified result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(1, 2);
+    /// let b = Fraction::unchecked_from(1, 3);
+    /// 
+    /// assert_eq!(a.checked_sub(b), Ok(Fraction::unchecked_from(1, 6)));
+    /// assert_eq!(Fraction::from_i32(i32::MIN).checked_sub(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_sub(self, rhs: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericFraction::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two fractions, returning `ArithmeticError::Overflow` if the
+    /// simplified result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(65536, 3);
+    /// let b = Fraction::unchecked_from(3, 65536);
+    /// 
+    /// assert_eq!(a.checked_mul(b), Ok(Fraction::from_i32(1)));
+    /// assert_eq!(a.checked_mul(a), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_mul(self, rhs: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        self.checked_operation(rhs, GenericFraction::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, returning `ArithmeticError::DivByZero` if `rhs` is 0
+    /// and `ArithmeticError::Overflow` if the simplified result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(1, 2);
+    /// let b = Fraction::unchecked_from(1, 3);
+    /// 
+    /// assert_eq!(a.checked_div(b), Ok(Fraction::unchecked_from(3, 2)));
+    /// assert_eq!(a.checked_div(Fraction::from_i32(0)), Err(ArithmeticError::DivByZero));
+    /// ```
+    pub fn checked_div(self, rhs: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if rhs.numerator == T::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        self.checked_operation(rhs, GenericFraction::div_fast, |a, b| a / b)
+    }
+
+    /// Returns the remainder of dividing `self` by `rhs` with the quotient rounded towards
+    /// zero, which is `self - rhs * (self / rhs).trunc()` and has the same sign as `self`. 
+    /// Returns `ArithmeticError::DivByZero` if `rhs` is 0 and `ArithmeticError::Overflow`
+    /// if the simplified result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let a = Fraction::unchecked_from(5, 3);
+    /// 
+    /// assert_eq!(a.checked_rem(Fraction::unchecked_from(1, 2)), Ok(Fraction::unchecked_from(1, 6)));
+    /// assert_eq!(a.checked_rem(Fraction::from_i32(0)), Err(ArithmeticError::DivByZero));
+    /// ```
+    pub fn checked_rem(self, rhs: GenericFraction<T>) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        self.integer_remainder(rhs, RoundingMode::TowardZero)
+    }
+
+    /// Negates the fraction, returning `ArithmeticError::Overflow` if the result can
+    /// not be represented, which is only the case for `T::MIN` over an odd denominator. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(1, 2).checked_neg(), Ok(Fraction::unchecked_from(-1, 2)));
+    /// assert_eq!(Fraction::unchecked_from(i32::MIN, 2).checked_neg(), Ok(Fraction::from_i32(1 << 30)));
+    /// assert_eq!(Fraction::from_i32(i32::MIN).checked_neg(), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_neg(self) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        let value = if self.numerator == T::MIN && self.denominator != T::Unsigned::ZERO { self.simplify() } else { self };
+
+        let numerator = value.numerator.checked_neg().ok_or(ArithmeticError::Overflow)?;
+
+        Ok(GenericFraction::unchecked_from(numerator, value.denominator))
+    }
+
+    /// Returns the absolute value of the fraction, or `ArithmeticError::Overflow` if it
+    /// can not be represented, which is only the case for `T::MIN` over an odd denominator. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// assert_eq!(Fraction::unchecked_from(-1, 2).checked_abs(), Ok(Fraction::unchecked_from(1, 2)));
+    /// assert_eq!(Fraction::unchecked_from(i32::MIN, 2).checked_abs(), Ok(Fraction::from_i32(1 << 30)));
+    /// assert_eq!(Fraction::from_i32(i32::MIN).checked_abs(), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_abs(self) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if self.numerator < T::ZERO { self.checked_neg() } else { Ok(self) }
+    }
+
+    /// Raises the fraction to the power of `exponent`, taking the reciprocal for
+    /// negative exponents. 
+    /// Returns `ArithmeticError::DivByZero` if 0 is raised to a negative power, and
+    /// `ArithmeticError::Overflow` if the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::fraction::ArithmeticError;
+    /// 
+    /// let fraction = Fraction::unchecked_from(-2, 3);
+    /// 
+    /// assert_eq!(fraction.checked_pow(3), Ok(Fraction::unchecked_from(-8, 27)));
+    /// assert_eq!(fraction.checked_pow(-2), Ok(Fraction::unchecked_from(9, 4)));
+    /// assert_eq!(fraction.checked_pow(100), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_pow(self, exponent: i32) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if self.denominator == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let mut base = if exponent < 0
+        {
+            let denominator = signed_from_unsigned::<T>(self.denominator).ok_or(ArithmeticError::Overflow)?;
+
+            GenericFraction::unsimplified_from(denominator * self.numerator.signum(), self.numerator.unsigned_abs())?
+        }
+        else
+        {
+            self
+        };
+
+        let mut result = GenericFraction::from_integer(T::ONE);
+        let mut exponent = exponent.unsigned_abs();
+
+        while exponent > 0
+        {
+            if exponent & 1 == 1
+            {
+                result = result.checked_mul(base)?;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0
+            {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Adds two fractions, saturating at the numeric bounds instead of overflowing. 
+    /// If the result is within the bounds but can not be represented exactly, the
+    /// closest representable fraction is returned. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MAX);
+    /// 
+    /// assert_eq!(a.saturating_add(a), Fraction::from_i32(i32::MAX));
+    /// ```
+    pub fn saturating_add(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.saturating_operation(rhs, GenericFraction::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, saturating at the numeric bounds instead of
+    /// overflowing. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MIN);
+    /// 
+    /// assert_eq!(a.saturating_sub(Fraction::from_i32(1)), Fraction::from_i32(i32::MIN));
+    /// ```
+    pub fn saturating_sub(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.saturating_operation(rhs, GenericFraction::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two fractions, saturating at the numeric bounds instead of
+    /// overflowing. 
+    /// If the result is within the bounds but can not be represented exactly, the
+    /// closest representable fraction is returned. 
+    /// 
+    /// ```
+    /// use complex::GenericFraction;
+    /// 
+    /// let a = GenericFraction::<i8>::unchecked_from(-100, 3);
+    /// let b = GenericFraction::<i8>::unchecked_from(1, 127);
+    /// 
+    /// assert_eq!(a.saturating_mul(a).get_components(), (i8::MAX, 1));
+    /// assert_eq!(a.saturating_mul(b).get_components(), (-58, 221));
+    /// ```
+    pub fn saturating_mul(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.saturating_operation(rhs, GenericFraction::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, saturating at the numeric bounds instead of
+    /// overflowing. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MAX);
+    /// 
+    /// assert_eq!(a.saturating_div(Fraction::unchecked_from(-1, 2)), Fraction::from_i32(i32::MIN));
+    /// ```
+    pub fn saturating_div(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        assert!(rhs.numerator != T::ZERO, "attempt to divide by zero");
+
+        self.saturating_operation(rhs, GenericFraction::div_fast, |a, b| a / b)
+    }
+
+    /// Adds two fractions, wrapping the numerator and denominator of the simplified
+    /// result around at the bounds of their types. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MAX);
+    /// 
+    /// assert_eq!(a.wrapping_add(Fraction::from_i32(1)), Fraction::from_i32(i32::MIN));
+    /// ```
+    pub fn wrapping_add(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtracts `rhs` from `self`, wrapping the numerator and denominator of the
+    /// simplified result around at the bounds of their types. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MIN);
+    /// 
+    /// assert_eq!(a.wrapping_sub(Fraction::from_i32(1)), Fraction::from_i32(i32::MAX));
+    /// ```
+    pub fn wrapping_sub(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiplies two fractions, wrapping the numerator and denominator of the
+    /// simplified result around at the bounds of their types. 
+    /// Note that the denominator of the result may wrap around to 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(3, 65536);
+    /// 
+    /// assert_eq!(a.wrapping_mul(a).get_components(), (9, 0));
+    /// ```
+    pub fn wrapping_mul(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Divides `self` by `rhs`, wrapping the numerator and denominator of the
+    /// simplified result around at the bounds of their types. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MIN);
+    /// 
+    /// assert_eq!(a.wrapping_div(Fraction::from_i32(-1)), Fraction::from_i32(i32::MIN));
+    /// ```
+    pub fn wrapping_div(self, rhs: GenericFraction<T>) -> GenericFraction<T>
+    {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Adds two fractions, returning the result of `wrapping_add` along with a
+    /// boolean indicating whether the result overflowed. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::unchecked_from(1, 2);
+    /// 
+    /// assert_eq!(a.overflowing_add(a), (Fraction::from_i32(1), false));
+    /// assert_eq!(Fraction::from_i32(i32::MAX).overflowing_add(Fraction::from_i32(1)), (Fraction::from_i32(i32::MIN), true));
+    /// ```
+    pub fn overflowing_add(self, rhs: GenericFraction<T>) -> (GenericFraction<T>, bool)
+    {
+        self.overflowing_operation(rhs, GenericFraction::add_fast, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from `self`, returning the result of `wrapping_sub` along
+    /// with a boolean indicating whether the result overflowed. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MIN);
+    /// 
+    /// assert_eq!(a.overflowing_sub(Fraction::from_i32(1)), (Fraction::from_i32(i32::MAX), true));
+    /// ```
+    pub fn overflowing_sub(self, rhs: GenericFraction<T>) -> (GenericFraction<T>, bool)
+    {
+        self.overflowing_operation(rhs, GenericFraction::sub_fast, |a, b| a - b)
+    }
+
+    /// Multiplies two fractions, returning the result of `wrapping_mul` along with
+    /// a boolean indicating whether the result overflowed. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(65536);
+    /// 
+    /// assert_eq!(a.overflowing_mul(a), (Fraction::from_i32(0), true));
+    /// ```
+    pub fn overflowing_mul(self, rhs: GenericFraction<T>) -> (GenericFraction<T>, bool)
+    {
+        self.overflowing_operation(rhs, GenericFraction::mul_fast, |a, b| a * b)
+    }
+
+    /// Divides `self` by `rhs`, returning the result of `wrapping_div` along with
+    /// a boolean indicating whether the result overflowed. 
+    /// 
+    /// Panics if `rhs` is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let a = Fraction::from_i32(i32::MIN);
+    /// 
+    /// assert_eq!(a.overflowing_div(Fraction::from_i32(-1)), (Fraction::from_i32(i32::MIN), true));
+    /// ```
+    pub fn overflowing_div(self, rhs: GenericFraction<T>) -> (GenericFraction<T>, bool)
+    {
+        assert!(rhs.numerator != T::ZERO, "attempt to divide by zero");
+
+        self.overflowing_operation(rhs, GenericFraction::div_fast, |a, b| a / b)
+    }
+
+    /// Applies `fast` to the operands, falling back to computing the exact result
+    /// with `exact` if any intermediate value overflows. 
+    fn checked_operation
+    (
+        self,
+        rhs: GenericFraction<T>,
+        fast: fn(Self, Self) -> Option<Self>,
+        exact: fn(BigFraction, BigFraction) -> BigFraction,
+    ) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if self.denominator == T::Unsigned::ZERO || rhs.denominator == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        match fast(self, rhs)
+        {
+            Some(value) => Ok(value),
+            None => GenericFraction::try_from(exact(self.into(), rhs.into())).map_err(|_| ArithmeticError::Overflow),
+        }
+    }
+
+    fn saturating_operation
+    (
+        self,
+        rhs: GenericFraction<T>,
+        fast: fn(Self, Self) -> Option<Self>,
+        exact: fn(BigFraction, BigFraction) -> BigFraction,
+    ) -> GenericFraction<T>
+    {
+        fast(self, rhs).unwrap_or_else(|| GenericFraction::from_big_saturating(&exact(self.into(), rhs.into())))
+    }
+
+    fn overflowing_operation
+    (
+        self,
+        rhs: GenericFraction<T>,
+        fast: fn(Self, Self) -> Option<Self>,
+        exact: fn(BigFraction, BigFraction) -> BigFraction,
+    ) -> (GenericFraction<T>, bool)
+    {
+        match fast(self, rhs)
+        {
+            Some(value) => (value, false),
+            None => GenericFraction::from_big_wrapping(&exact(self.into(), rhs.into())),
+        }
+    }
+
+    /// Adds using only the backing integer type, returning `None` if any
+    /// intermediate value overflows. 
+    fn add_fast(self, rhs: GenericFraction<T>) -> Option<GenericFraction<T>>
+    {
+        let denominator_gcd = gcd(self.denominator, rhs.denominator);
+
+        let numerator = self.numerator.checked_mul(signed_from_unsigned(rhs.denominator / denominator_gcd)?)?
+            .checked_add(rhs.numerator.checked_mul(signed_from_unsigned(self.denominator / denominator_gcd)?)?)?;
+
+        let denominator = (self.denominator / denominator_gcd).checked_mul(rhs.denominator)?;
+
+        GenericFraction::from(numerator, denominator).ok()
+    }
+
+    fn sub_fast(self, rhs: GenericFraction<T>) -> Option<GenericFraction<T>>
+    {
+        GenericFraction::add_fast(self, GenericFraction::unchecked_from(rhs.numerator.checked_neg()?, rhs.denominator))
+    }
+
+    fn mul_fast(self, rhs: GenericFraction<T>) -> Option<GenericFraction<T>>
+    {
+        // Cancelling common factors across the two fractions before multiplying means
+        // the products only overflow if the simplified result can not be represented.
+        let self_gcd = gcd(self.numerator.unsigned_abs(), rhs.denominator);
+        let rhs_gcd = gcd(rhs.numerator.unsigned_abs(), self.denominator);
+
+        let numerator = divide_by_unsigned(self.numerator, self_gcd).checked_mul(divide_by_unsigned(rhs.numerator, rhs_gcd))?;
+        let denominator = (self.denominator / rhs_gcd).checked_mul(rhs.denominator / self_gcd)?;
+
+        GenericFraction::from(numerator, denominator).ok()
+    }
+
+    fn div_fast(self, rhs: GenericFraction<T>) -> Option<GenericFraction<T>>
+    {
+        let denominator = signed_from_unsigned::<T>(rhs.denominator)?;
+        let reciprocal = GenericFraction::unsimplified_from(denominator * rhs.numerator.signum(), rhs.numerator.unsigned_abs()).ok()?;
+
+        GenericFraction::mul_fast(self, reciprocal)
+    }
+
+    /// Finds the fraction closest to `value` with a denominator of at most `max_denominator`,
+    /// returning `None` if `value` is out of range or `max_denominator` is 0. 
+    pub(crate) fn from_big_limited(value: &BigFraction, max_denominator: T::Unsigned) -> Option<GenericFraction<T>>
+    {
+        if max_denominator == T::Unsigned::ZERO
+        {
+            return None;
+        }
+
+        let negative = value.get_numerator().is_negative();
+        let numerator_bound = if negative { T::MIN.unsigned_abs() } else { T::MAX.unsigned_abs() }.into();
+
+        if *value.get_numerator().magnitude() > BigUint::from(numerator_bound) * value.get_denominator()
+        {
+            return None;
+        }
+
+        let (numerator, denominator) = closest_bounded(&value.abs(), numerator_bound, max_denominator.into());
+
+        Some(GenericFraction::unchecked_from
+        (
+            signed_from_magnitude(negative, numerator)?,
+            T::Unsigned::try_from(denominator).ok()?
+        ))
+    }
+
+    /// Converts an exact result to this type, clamping it to the numeric bounds if it
+    /// is out of range, and otherwise finding the closest representable fraction. 
+    pub(crate) fn from_big_saturating(value: &BigFraction) -> GenericFraction<T>
+    {
+        if let Ok(fraction) = GenericFraction::try_from(value.clone())
+        {
+            return fraction;
+        }
+
+        let max = GenericFraction::from_integer(T::MAX);
+        let min = GenericFraction::from_integer(T::MIN);
+
+        if *value >= max.into()
+        {
+            return max;
+        }
+
+        if *value <= min.into()
+        {
+            return min;
+        }
+
+        let negative = value.get_numerator().is_negative();
+
+        let numerator_bound = if negative { T::MIN.unsigned_abs() } else { T::MAX.unsigned_abs() }.into();
+        let denominator_bound = T::Unsigned::MAX.into();
+
+        let (numerator, denominator) = closest_bounded(&value.abs(), numerator_bound, denominator_bound);
+
+        let numerator = if negative { 0i128.wrapping_sub_unsigned(numerator) } else { numerator as i128 };
+
+        GenericFraction::unchecked_from(T::from_i128_wrapping(numerator), T::Unsigned::from_u128_wrapping(denominator))
+    }
+
+    /// Converts an exact result to this type, wrapping the numerator and denominator
+    /// around at the bounds of their types, along with a boolean indicating whether
+    /// any wrapping occured. 
+    pub(crate) fn from_big_wrapping(value: &BigFraction) -> (GenericFraction<T>, bool)
+    {
+        if let Ok(fraction) = GenericFraction::try_from(value.clone())
+        {
+            return (fraction, false);
+        }
+
+        let (numerator, denominator) = value.get_components();
+
+        let fraction = GenericFraction::unchecked_from
+        (
+            T::from_i128_wrapping(numerator.low_i128()),
+            T::Unsigned::from_u128_wrapping(denominator.low_u128())
+        );
+
+        (fraction, true)
+    }
+}
+
+/// Finds the fraction closest to the non-negative `value` with a numerator of at most
+/// `numerator_bound` and a denominator of at most `denominator_bound`, by walking the
+/// convergents and semiconvergents of its continued fraction expansion. 
+fn closest_bounded(value: &BigFraction, numerator_bound: u128, denominator_bound: u128) -> (u128, u128)
+{
+    let (mut remaining_numerator, mut remaining_denominator) =
+        (value.get_numerator().unsigned_abs(), value.get_denominator().clone());
+
+    let (mut previous_numerator, mut previous_denominator) = (0u128, 1u128);
+    let (mut numerator, mut denominator) = (1u128, 0u128);
+
+    loop
+    {
+        let (term, remainder) = remaining_numerator.div_rem(&remaining_denominator);
+
+        let next = term.to_u128().and_then(|term| Some
+        ((
+            term.checked_mul(numerator)?.checked_add(previous_numerator)?,
+            term.checked_mul(denominator)?.checked_add(previous_denominator)?,
+        )));
+
+        match next
+        {
+            Some((next_numerator, next_denominator))
+                if next_numerator <= numerator_bound && next_denominator <= denominator_bound =>
+            {
+                (previous_numerator, previous_denominator) = (numerator, denominator);
+                (numerator, denominator) = (next_numerator, next_denominator);
+
+                if remainder.is_zero()
+                {
+                    return (numerator, denominator);
+                }
+
+                remaining_numerator = remaining_denominator;
+                remaining_denominator = remainder;
+            }
+            _ =>
+            {
+                let steps = |bound: u128, previous: u128, current: u128|
+                    (bound - previous).checked_div(current).unwrap_or(u128::MAX);
+
+                let scale = steps(numerator_bound, previous_numerator, numerator)
+                    .min(steps(denominator_bound, previous_denominator, denominator));
+
+                let semiconvergent =
+                    (previous_numerator + scale * numerator, previous_denominator + scale * denominator);
+
+                if semiconvergent.1 == 0
+                {
+                    return (numerator, denominator);
+                }
+
+                let distance = |(numerator, denominator): (u128, u128)|
+                {
+                    let fraction = BigFraction::from(BigInt::from(numerator), BigUint::from(denominator))
+                        .expect("Denominator will not be 0");
+
+                    (&fraction - value).abs()
+                };
+
+                if distance(semiconvergent) < distance((numerator, denominator))
+                {
+                    return semiconvergent;
+                }
+
+                return (numerator, denominator);
+            }
+        }
+    }
+}
+
+/// Converts an exact integer result to `T`, if it is in range. 
+fn integer_from_big<T: SignedInteger>(value: BigInt) -> Result<T, ArithmeticError>
+{
+    value.to_i128().and_then(|value| T::try_from(value).ok()).ok_or(ArithmeticError::Overflow)
+}
+
+/// Converts a sign and magnitude to a signed integer, if it is in range. 
+pub(crate) fn signed_from_magnitude<T: SignedInteger>(negative: bool, magnitude: u128) -> Option<T>
+{
+    let value = if negative { 0i128.checked_sub_unsigned(magnitude)? } else { i128::try_from(magnitude).ok()? };
+
+    T::try_from(value).ok()
+}
+
+/// converts an unsigned value to the matching signed type if it is in range
+fn signed_from_unsigned<T: SignedInteger>(value: T::Unsigned) -> Option<T>
+{
+    i128::try_from(value.into()).ok().and_then(|value| T::try_from(value).ok())
+}
+
+/// finds the integer `root` such that `root` to the power of `n` is `value`, if there is one
+fn exact_integer_root<U: UnsignedInteger>(value: U, n: u32) -> Option<U>
+{
+    let two = U::ONE + U::ONE;
+
+    // binary search for the largest integer whose `n`th power is at most `value`
+    let (mut low, mut high) = (U::ZERO, value);
+
+    while low < high
+    {
+        let middle = high - (high - low) / two;
+
+        match checked_integer_pow(middle, n)
+        {
+            Some(power) if power <= value => low = middle,
+            _ => high = middle - U::ONE,
+        }
+    }
+
+    (checked_integer_pow(low, n) == Some(value)).then_some(low)
+}
+
+/// raises `base` to the power of `exponent`, returning `None` if it overflows
+fn checked_integer_pow<U: UnsignedInteger>(base: U, exponent: u32) -> Option<U>
+{
+    if base <= U::ONE
+    {
+        return Some(if exponent == 0 { U::ONE } else { base });
     }
 
-    result
+    (0..exponent).try_fold(U::ONE, |result, _| result.checked_mul(base))
 }
 
-/// returns a pair with the smallest value first
-const fn get_ordering(a: u32, b: u32) -> (u32, u32)
+/// divides a signed value by an unsigned divisor of its magnitude
+fn divide_by_unsigned<T: SignedInteger>(value: T, divisor: T::Unsigned) -> T
 {
-    if a < b
+    // the divisor can only be larger than `T::MAX` if the value is 0 or `T::MIN`,
+    // in which case the result is just the sign
+    if divisor > T::MAX.unsigned_abs()
     {
-        (a, b)
+        value.signum()
     }
     else
     {
-        (b, a)
+        value / T::from_unsigned(divisor)
     }
 }
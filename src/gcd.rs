@@ -0,0 +1,160 @@
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// Computes the greatest common divisor of two numbers. 
+/// The result is 0 only if both numbers are 0. 
+/// 
+/// ```
+/// use complex::gcd::gcd;
+/// 
+/// assert_eq!(gcd(12u32, 18), 6);
+/// assert_eq!(gcd(0u64, 5), 5);
+/// ```
+pub fn gcd<U: UnsignedInteger>(a: U, b: U) -> U
+{
+    a.gcd(b)
+}
+
+/// Computes the least common multiple of two numbers, which is 0 if either number is 0. 
+/// 
+/// Panics if the result overflows in debug mode. 
+/// 
+/// ```
+/// use complex::gcd::lcm;
+/// 
+/// assert_eq!(lcm(4u32, 6), 12);
+/// assert_eq!(lcm(0u8, 6), 0);
+/// ```
+pub fn lcm<U: UnsignedInteger>(a: U, b: U) -> U
+{
+    if a == U::ZERO || b == U::ZERO
+    {
+        return U::ZERO;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// Computes the greatest common divisor `g` of two numbers along with a pair of
+/// coefficients `x` and `y` such that `a * x + b * y == g`. 
+/// 
+/// ```
+/// use complex::gcd::extended_gcd;
+/// 
+/// let (gcd, x, y) = extended_gcd(240i32, -46);
+/// 
+/// assert_eq!(gcd, 2);
+/// assert_eq!(240 * x - 46 * y, 2);
+/// ```
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T::Unsigned, T, T)
+{
+    a.extended_gcd(b)
+}
+
+macro_rules! impl_gcd
+{
+    ($unsigned:ty, $signed:ty, $gcd:ident, $lcm:ident, $extended_gcd:ident) =>
+    {
+        #[doc = concat!("Computes the greatest common divisor of two `", stringify!($unsigned), "`s with ")]
+        /// Stein's binary algorithm, which only uses shifts and subtraction. 
+        /// 
+        /// ```
+        #[doc = concat!("use complex::gcd::", stringify!($gcd), ";")]
+        /// 
+        #[doc = concat!("assert_eq!(", stringify!($gcd), "(48, 18), 6);")]
+        #[doc = concat!("assert_eq!(", stringify!($gcd), "(0, 7), 7);")]
+        /// ```
+        pub const fn $gcd(mut a: $unsigned, mut b: $unsigned) -> $unsigned
+        {
+            if a == 0
+            {
+                return b;
+            }
+
+            if b == 0
+            {
+                return a;
+            }
+
+            // the largest power of 2 that divides both numbers
+            let shift = (a | b).trailing_zeros();
+
+            a >>= a.trailing_zeros();
+
+            loop
+            {
+                b >>= b.trailing_zeros();
+
+                if a > b
+                {
+                    let temporary = a;
+                    a = b;
+                    b = temporary;
+                }
+
+                b -= a;
+
+                if b == 0
+                {
+                    return a << shift;
+                }
+            }
+        }
+
+        #[doc = concat!("Computes the least common multiple of two `", stringify!($unsigned), "`s, which is 0 if either is 0. ")]
+        /// 
+        /// Panics if the result overflows in debug mode. 
+        /// 
+        /// ```
+        #[doc = concat!("use complex::gcd::", stringify!($lcm), ";")]
+        /// 
+        #[doc = concat!("assert_eq!(", stringify!($lcm), "(4, 6), 12);")]
+        /// ```
+        pub const fn $lcm(a: $unsigned, b: $unsigned) -> $unsigned
+        {
+            if a == 0 || b == 0
+            {
+                return 0;
+            }
+
+            a / $gcd(a, b) * b
+        }
+
+        #[doc = concat!("Computes the greatest common divisor `g` of two `", stringify!($signed), "`s along with a pair")]
+        /// of coefficients `x` and `y` such that `a * x + b * y == g`, using the extended
+        /// euclidean algorithm. 
+        /// 
+        /// ```
+        #[doc = concat!("use complex::gcd::", stringify!($extended_gcd), ";")]
+        /// 
+        #[doc = concat!("let (gcd, x, y) = ", stringify!($extended_gcd), "(-12, 42);")]
+        /// 
+        /// assert_eq!(gcd, 6);
+        /// assert_eq!(-12 * x + 42 * y, 6);
+        /// ```
+        pub const fn $extended_gcd(a: $signed, b: $signed) -> ($unsigned, $signed, $signed)
+        {
+            let (mut previous_remainder, mut remainder) = (a.unsigned_abs(), b.unsigned_abs());
+            let (mut previous_x, mut x): ($signed, $signed) = (1, 0);
+            let (mut previous_y, mut y): ($signed, $signed) = (0, 1);
+
+            // The coefficients that are returned always fit, but the ones calculated in the
+            // final step can overflow, so wrapping arithmetic is used throughout.
+            while remainder != 0
+            {
+                let quotient = (previous_remainder / remainder) as $signed;
+
+                (previous_remainder, remainder) = (remainder, previous_remainder % remainder);
+                (previous_x, x) = (x, previous_x.wrapping_sub(quotient.wrapping_mul(x)));
+                (previous_y, y) = (y, previous_y.wrapping_sub(quotient.wrapping_mul(y)));
+            }
+
+            (previous_remainder, previous_x.wrapping_mul(a.signum()), previous_y.wrapping_mul(b.signum()))
+        }
+    };
+}
+
+impl_gcd!(u8, i8, gcd_u8, lcm_u8, extended_gcd_i8);
+impl_gcd!(u16, i16, gcd_u16, lcm_u16, extended_gcd_i16);
+impl_gcd!(u32, i32, gcd_u32, lcm_u32, extended_gcd_i32);
+impl_gcd!(u64, i64, gcd_u64, lcm_u64, extended_gcd_i64);
+impl_gcd!(u128, i128, gcd_u128, lcm_u128, extended_gcd_i128);
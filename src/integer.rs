@@ -0,0 +1,196 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+/// A signed primitive integer that can be used as the numerator of a `GenericFraction`. 
+/// Each signed integer is paired with an unsigned integer of the same width, which is
+/// used for the denominator. 
+pub trait SignedInteger:
+    Copy + Ord + Debug + Display + FromStr + Into<i128> + TryFrom<i128>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+    + Rem<Output = Self> + Neg<Output = Self>
+{
+    /// The unsigned integer with the same width as this type. 
+    type Unsigned: UnsignedInteger<Signed = Self>;
+
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value. 
+    fn signum(self) -> Self;
+
+    /// Returns the absolute value as the matching unsigned type, which can not overflow. 
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// Converts an unsigned value to this type with an `as` cast. 
+    fn from_unsigned(value: Self::Unsigned) -> Self;
+
+    /// Converts an `i128` to this type with an `as` cast, wrapping around at the bounds. 
+    fn from_i128_wrapping(value: i128) -> Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_neg(self) -> Option<Self>;
+
+    /// Computes the greatest common divisor of the two numbers along with their
+    /// bezout coefficients, see `gcd::extended_gcd`. 
+    fn extended_gcd(self, other: Self) -> (Self::Unsigned, Self, Self);
+
+    /// Converts the value to an `f64` with an `as` cast. 
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to this type with an `as` cast. 
+    fn from_f64(value: f64) -> Self;
+}
+
+/// An unsigned primitive integer that can be used as the denominator of a `GenericFraction`. 
+pub trait UnsignedInteger:
+    Copy + Ord + Debug + Display + FromStr + Into<u128> + TryFrom<u128>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The signed integer with the same width as this type. 
+    type Signed: SignedInteger<Unsigned = Self>;
+
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    /// Converts a `u128` to this type with an `as` cast, wrapping around at the bounds. 
+    fn from_u128_wrapping(value: u128) -> Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Computes the greatest common divisor of the two numbers, see `gcd::gcd`. 
+    fn gcd(self, other: Self) -> Self;
+
+    /// Converts the value to an `f64` with an `as` cast. 
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to this type with an `as` cast. 
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_integer
+{
+    ($signed:ty, $unsigned:ty, $gcd:ident, $extended_gcd:ident) =>
+    {
+        impl SignedInteger for $signed
+        {
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$signed>::MIN;
+            const MAX: Self = <$signed>::MAX;
+
+            fn signum(self) -> Self
+            {
+                <$signed>::signum(self)
+            }
+
+            fn unsigned_abs(self) -> Self::Unsigned
+            {
+                <$signed>::unsigned_abs(self)
+            }
+
+            fn from_unsigned(value: Self::Unsigned) -> Self
+            {
+                value as $signed
+            }
+
+            fn from_i128_wrapping(value: i128) -> Self
+            {
+                value as $signed
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self>
+            {
+                <$signed>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self>
+            {
+                <$signed>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self>
+            {
+                <$signed>::checked_mul(self, rhs)
+            }
+
+            fn checked_neg(self) -> Option<Self>
+            {
+                <$signed>::checked_neg(self)
+            }
+
+            fn extended_gcd(self, other: Self) -> (Self::Unsigned, Self, Self)
+            {
+                crate::gcd::$extended_gcd(self, other)
+            }
+
+            fn to_f64(self) -> f64
+            {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self
+            {
+                value as $signed
+            }
+        }
+
+        impl UnsignedInteger for $unsigned
+        {
+            type Signed = $signed;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$unsigned>::MAX;
+
+            fn from_u128_wrapping(value: u128) -> Self
+            {
+                value as $unsigned
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self>
+            {
+                <$unsigned>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self>
+            {
+                <$unsigned>::checked_mul(self, rhs)
+            }
+
+            fn gcd(self, other: Self) -> Self
+            {
+                crate::gcd::$gcd(self, other)
+            }
+
+            fn to_f64(self) -> f64
+            {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self
+            {
+                value as $unsigned
+            }
+        }
+    };
+}
+
+impl_integer!(i8, u8, gcd_u8, extended_gcd_i8);
+impl_integer!(i16, u16, gcd_u16, extended_gcd_i16);
+impl_integer!(i32, u32, gcd_u32, extended_gcd_i32);
+impl_integer!(i64, u64, gcd_u64, extended_gcd_i64);
+impl_integer!(i128, u128, gcd_u128, extended_gcd_i128);
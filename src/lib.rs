@@ -1,5 +1,47 @@
+pub mod integer;
+pub use crate::integer::{SignedInteger, UnsignedInteger};
+
+pub mod gcd;
+
+pub mod rounding;
+pub use crate::rounding::RoundingMode;
+
 pub mod fraction;
-pub use crate::fraction::Fraction;
+pub use crate::fraction::{Fraction, GenericFraction};
 
 pub mod complex;
-pub use crate::complex::Complex;
+pub use crate::complex::{Complex, GenericComplex};
+
+pub mod field;
+pub use crate::field::{ComplexField, Field, OrderedField, Ring};
+
+pub mod mixed;
+pub use crate::mixed::{GenericMixedNumber, MixedNumber};
+
+pub mod big_integer;
+pub use crate::big_integer::{BigInt, BigUint};
+
+pub mod big_fraction;
+pub use crate::big_fraction::BigFraction;
+
+pub mod big_complex;
+pub use crate::big_complex::BigComplex;
+
+pub mod continued_fraction;
+
+pub mod farey;
+
+pub mod egyptian;
+
+pub mod stern_brocot;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+mod accumulate;
+mod decimal;
+mod float;
+#[cfg(feature = "num-traits")]
+mod num;
+mod ops;
+mod parse;
@@ -15,7 +15,7 @@ fn main()
 
         let float_value = 144.2;
 
-        let c = Fraction::from_f64(float_value, 0.000000001);
+        let c = Fraction::approximate_f64(float_value, 1000).unwrap();
 
         println!("{float_value} = {c}");
         println!("{c} = {}", c.to_f64());
@@ -0,0 +1,153 @@
+use crate::fraction::{GenericFraction, ParseFractionError};
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// A rational number written as a whole number and a proper fraction, such as `-2 3/4`. 
+/// The whole part is rounded towards zero and the fraction has the same sign as the
+/// value, so `-2 3/4` is `-2 + -3/4`, which is the same form that `{:#}` writes fractions in. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenericMixedNumber<T: SignedInteger>
+{
+    whole: T,
+    fraction: GenericFraction<T>,
+}
+
+/// A mixed number with an `i32` whole part and a `Fraction`. 
+pub type MixedNumber = GenericMixedNumber<i32>;
+
+impl<T: SignedInteger> GenericMixedNumber<T>
+{
+    /// Creates a mixed number from its parts, returning `None` if the fraction is not
+    /// proper, has 0 for its denominator, or has a different sign than a non-zero `whole`. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, MixedNumber};
+    /// 
+    /// let mixed = MixedNumber::from(-2, Fraction::unchecked_from(-3, 4)).unwrap();
+    /// 
+    /// let fraction: Fraction = mixed.into();
+    /// 
+    /// assert_eq!(fraction, Fraction::unchecked_from(-11, 4));
+    /// assert_eq!(MixedNumber::from(-2, Fraction::unchecked_from(3, 4)), None);
+    /// assert_eq!(MixedNumber::from(1, Fraction::unchecked_from(5, 4)), None);
+    /// ```
+    pub fn from(whole: T, fraction: GenericFraction<T>) -> Option<GenericMixedNumber<T>>
+    {
+        let (numerator, denominator) = fraction.get_components();
+
+        let proper = denominator != T::Unsigned::ZERO && numerator.unsigned_abs() < denominator;
+        let same_sign = whole == T::ZERO || numerator == T::ZERO || (whole < T::ZERO) == (numerator < T::ZERO);
+
+        (proper && same_sign).then(|| GenericMixedNumber {whole, fraction: fraction.simplify()})
+    }
+
+    /// Returns the whole part and the fractional part. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, MixedNumber};
+    /// 
+    /// let mixed: MixedNumber = Fraction::unchecked_from(7, 2).into();
+    /// 
+    /// assert_eq!(mixed.get_components(), (3, Fraction::unchecked_from(1, 2)));
+    /// ```
+    pub const fn get_components(&self) -> (T, GenericFraction<T>)
+    {
+        (self.whole, self.fraction)
+    }
+
+    /// Returns the whole part, which is rounded towards zero. 
+    pub const fn get_whole(&self) -> T
+    {
+        self.whole
+    }
+
+    /// Returns the fractional part, which has the same sign as the value. 
+    pub const fn get_fraction(&self) -> GenericFraction<T>
+    {
+        self.fraction
+    }
+}
+
+impl<T: SignedInteger> From<GenericFraction<T>> for GenericMixedNumber<T>
+{
+    /// Splits a fraction into its whole part and its fractional part. 
+    /// Panics if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, MixedNumber};
+    /// 
+    /// let mixed: MixedNumber = Fraction::unchecked_from(-11, 4).into();
+    /// 
+    /// assert_eq!(mixed.get_whole(), -2);
+    /// assert_eq!(mixed.get_fraction(), Fraction::unchecked_from(-3, 4));
+    /// ```
+    fn from(value: GenericFraction<T>) -> Self
+    {
+        GenericMixedNumber {whole: value.trunc().get_numerator(), fraction: value.fract()}
+    }
+}
+
+impl<T: SignedInteger> From<GenericMixedNumber<T>> for GenericFraction<T>
+{
+    /// Adds the whole part and the fractional part back together. 
+    /// Panics if the result can not be represented, which is only possible for mixed
+    /// numbers that were not created from a fraction. 
+    fn from(value: GenericMixedNumber<T>) -> Self
+    {
+        GenericFraction::from_integer(value.whole) + value.fraction
+    }
+}
+
+impl<T: SignedInteger> std::fmt::Display for GenericMixedNumber<T>
+{
+    /// Writes the mixed number as `w n/d`, leaving out a whole part of 0 or a fractional
+    /// part of 0. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, MixedNumber};
+    /// 
+    /// let mixed: MixedNumber = Fraction::unchecked_from(-11, 4).into();
+    /// 
+    /// assert_eq!(mixed.to_string(), "-2 3/4");
+    /// assert_eq!(MixedNumber::from(0, Fraction::unchecked_from(-3, 4)).unwrap().to_string(), "-3/4");
+    /// assert_eq!(MixedNumber::from(5, Fraction::from_i32(0)).unwrap().to_string(), "5");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let (numerator, denominator) = self.fraction.get_components();
+
+        if self.whole == T::ZERO
+        {
+            return write!(f, "{}", self.fraction);
+        }
+
+        if numerator == T::ZERO
+        {
+            return write!(f, "{}", self.whole);
+        }
+
+        let sign = if self.whole < T::ZERO { "-" } else { "" };
+
+        write!(f, "{sign}{} {}/{denominator}", self.whole.unsigned_abs(), numerator.unsigned_abs())
+    }
+}
+
+impl<T: SignedInteger> std::str::FromStr for GenericMixedNumber<T>
+{
+    type Err = ParseFractionError;
+
+    /// Parses a mixed number such as `-2 3/4`, or anything else that a fraction can be
+    /// parsed from, splitting it into its whole part and its fractional part. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, MixedNumber};
+    /// 
+    /// let mixed: MixedNumber = "-2 3/4".parse().unwrap();
+    /// 
+    /// assert_eq!(mixed.get_components(), (-2, Fraction::unchecked_from(-3, 4)));
+    /// assert_eq!("2.75".parse(), Ok(MixedNumber::from(2, Fraction::unchecked_from(3, 4)).unwrap()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        s.parse::<GenericFraction<T>>().map(Into::into)
+    }
+}
@@ -0,0 +1,476 @@
+//! Implementations of the `num-traits` traits, which can be used alongside the field
+//! traits, since none of their method names clash. 
+//! 
+//! ```
+//! use complex::{Complex, ComplexField, Field, Fraction, OrderedField, Ring};
+//! use num_traits::{Inv, One, Signed, Zero};
+//! 
+//! // uses methods from both sets of traits in the same generic function
+//! fn normalize<F: OrderedField + Signed>(value: F) -> F
+//! {
+//!     if value.is_zero() { value } else { value.abs() * value.magnitude().reciprocal().unwrap() }
+//! }
+//! 
+//! let half = Fraction::unchecked_from(-1, 2);
+//! 
+//! assert_eq!(normalize(half), Fraction::ONE);
+//! assert_eq!(normalize(Fraction::ZERO), Fraction::ZERO);
+//! assert!(half.is_negative() && !half.is_zero() && half.magnitude().is_positive());
+//! assert!(!Complex::I.conjugate().inv().is_one() && Complex::ZERO.is_zero());
+//! assert_eq!(Field::reciprocal(Complex::I), Ok(Complex::I.inv()));
+//! assert_eq!(<Fraction as Ring>::ONE, Fraction::one());
+//! ```
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, ConstOne, ConstZero, Euclid,
+    FromPrimitive, Inv, Num, NumCast, One, Pow, SaturatingAdd, SaturatingMul, SaturatingSub, Signed, ToPrimitive,
+    WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+use crate::complex::GenericComplex;
+use crate::fraction::{signed_from_magnitude, GenericFraction, ParseFractionError};
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+impl<T: SignedInteger> Zero for GenericFraction<T>
+{
+    fn zero() -> Self
+    {
+        GenericFraction::ZERO
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        self.get_numerator() == T::ZERO && self.get_denominator() != T::Unsigned::ZERO
+    }
+}
+
+impl<T: SignedInteger> ConstZero for GenericFraction<T>
+{
+    const ZERO: Self = GenericFraction::ZERO;
+}
+
+impl<T: SignedInteger> One for GenericFraction<T>
+{
+    fn one() -> Self
+    {
+        GenericFraction::ONE
+    }
+}
+
+impl<T: SignedInteger> ConstOne for GenericFraction<T>
+{
+    const ONE: Self = GenericFraction::ONE;
+}
+
+impl<T: SignedInteger> Num for GenericFraction<T>
+{
+    type FromStrRadixErr = ParseFractionError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr>
+    {
+        GenericFraction::from_str_radix(s, radix)
+    }
+}
+
+impl<T: SignedInteger> Signed for GenericFraction<T>
+{
+    /// Returns the absolute value, panicking if it can not be represented. 
+    fn abs(&self) -> Self
+    {
+        if self.is_negative() { -*self } else { *self }
+    }
+
+    /// Returns `self - other` if it is positive, and 0 otherwise. 
+    fn abs_sub(&self, other: &Self) -> Self
+    {
+        if self <= other { GenericFraction::ZERO } else { *self - *other }
+    }
+
+    fn signum(&self) -> Self
+    {
+        GenericFraction::from_integer(GenericFraction::signum(*self))
+    }
+
+    fn is_positive(&self) -> bool
+    {
+        self.get_numerator() > T::ZERO
+    }
+
+    fn is_negative(&self) -> bool
+    {
+        self.get_numerator() < T::ZERO
+    }
+}
+
+impl<T: SignedInteger> Inv for GenericFraction<T>
+{
+    type Output = GenericFraction<T>;
+
+    /// Returns the reciprocal, panicking if the fraction is 0. 
+    fn inv(self) -> Self::Output
+    {
+        GenericFraction::ONE / self
+    }
+}
+
+impl<T: SignedInteger> Bounded for GenericFraction<T>
+{
+    fn min_value() -> Self
+    {
+        GenericFraction::from_integer(T::MIN)
+    }
+
+    fn max_value() -> Self
+    {
+        GenericFraction::from_integer(T::MAX)
+    }
+}
+
+impl<T: SignedInteger> Euclid for GenericFraction<T>
+{
+    fn div_euclid(&self, v: &Self) -> Self
+    {
+        GenericFraction::from_integer(GenericFraction::div_euclid(*self, *v))
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self
+    {
+        GenericFraction::rem_euclid(*self, *v)
+    }
+}
+
+/// Implements a `num_traits` checked operator through the inherent method that returns a
+/// `Result`. 
+macro_rules! checked_operator
+{
+    ($type:ty, $trait:ident, $method:ident) =>
+    {
+        impl<T: SignedInteger> $trait for $type
+        {
+            fn $method(&self, v: &Self) -> Option<Self>
+            {
+                Self::$method(*self, *v).ok()
+            }
+        }
+    };
+}
+
+/// Implements a `num_traits` saturating or wrapping operator through the inherent method. 
+macro_rules! forward_operator
+{
+    ($type:ty, $trait:ident, $method:ident) =>
+    {
+        impl<T: SignedInteger> $trait for $type
+        {
+            fn $method(&self, v: &Self) -> Self
+            {
+                Self::$method(*self, *v)
+            }
+        }
+    };
+}
+
+checked_operator!(GenericFraction<T>, CheckedAdd, checked_add);
+checked_operator!(GenericFraction<T>, CheckedSub, checked_sub);
+checked_operator!(GenericFraction<T>, CheckedMul, checked_mul);
+checked_operator!(GenericFraction<T>, CheckedDiv, checked_div);
+checked_operator!(GenericFraction<T>, CheckedRem, checked_rem);
+
+forward_operator!(GenericFraction<T>, SaturatingAdd, saturating_add);
+forward_operator!(GenericFraction<T>, SaturatingSub, saturating_sub);
+forward_operator!(GenericFraction<T>, SaturatingMul, saturating_mul);
+forward_operator!(GenericFraction<T>, WrappingAdd, wrapping_add);
+forward_operator!(GenericFraction<T>, WrappingSub, wrapping_sub);
+forward_operator!(GenericFraction<T>, WrappingMul, wrapping_mul);
+
+impl<T: SignedInteger> CheckedNeg for GenericFraction<T>
+{
+    fn checked_neg(&self) -> Option<Self>
+    {
+        GenericFraction::checked_neg(*self).ok()
+    }
+}
+
+impl<T: SignedInteger> ToPrimitive for GenericFraction<T>
+{
+    /// Converts the fraction to an integer rounded towards zero, like casting a float,
+    /// returning `None` if it does not fit or the denominator is 0. 
+    fn to_i64(&self) -> Option<i64>
+    {
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128>
+    {
+        let (numerator, denominator): (T, u128) = (self.get_numerator(), self.get_denominator().into());
+        let magnitude: u128 = numerator.unsigned_abs().into();
+
+        signed_from_magnitude(numerator < T::ZERO, magnitude.checked_div(denominator)?)
+    }
+
+    fn to_u64(&self) -> Option<u64>
+    {
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128>
+    {
+        self.to_i128()?.try_into().ok()
+    }
+
+    /// Converts the fraction to the nearest `f32`, see `GenericFraction::to_f32`. 
+    fn to_f32(&self) -> Option<f32>
+    {
+        Some(GenericFraction::to_f32(self))
+    }
+
+    /// Converts the fraction to the nearest `f64`, see `GenericFraction::to_f64`. 
+    fn to_f64(&self) -> Option<f64>
+    {
+        Some(GenericFraction::to_f64(self))
+    }
+}
+
+impl<T: SignedInteger> FromPrimitive for GenericFraction<T>
+{
+    fn from_i64(n: i64) -> Option<Self>
+    {
+        GenericFraction::from_i128(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self>
+    {
+        T::try_from(n).ok().map(GenericFraction::from_integer)
+    }
+
+    fn from_u64(n: u64) -> Option<Self>
+    {
+        GenericFraction::from_i128(n.into())
+    }
+
+    fn from_u128(n: u128) -> Option<Self>
+    {
+        GenericFraction::from_i128(n.try_into().ok()?)
+    }
+
+    /// Converts a float to the closest fraction, which is exact whenever the float can be
+    /// represented, returning `None` for NaN, infinity and values that are out of range. 
+    fn from_f64(n: f64) -> Option<Self>
+    {
+        GenericFraction::approximate_f64(n, T::Unsigned::MAX)
+    }
+}
+
+impl<T: SignedInteger> NumCast for GenericFraction<T>
+{
+    /// Converts integers exactly and floats to the closest fraction, returning `None` if
+    /// the value is out of range or not a number. 
+    fn from<N: ToPrimitive>(n: N) -> Option<Self>
+    {
+        // integers and integral floats take the exact route, and other floats do not
+        // survive the round trip through an integer
+        match (n.to_i128(), n.to_f64())
+        {
+            (Some(integer), Some(float)) if integer as f64 == float => GenericFraction::from_i128(integer),
+            (_, Some(float)) if float.fract() != 0.0 => <Self as FromPrimitive>::from_f64(float),
+            _ => GenericFraction::from_u128(n.to_u128()?),
+        }
+    }
+}
+
+/// Implements `Pow` for exponent types that convert to an `i32` losslessly. 
+macro_rules! pow_signed
+{
+    ($type:ty, $($exponent:ty),*) =>
+    {
+        $(
+            impl<T: SignedInteger> Pow<$exponent> for $type
+            {
+                type Output = $type;
+
+                /// Raises the value to the power of `rhs`, panicking if it overflows or 0 is
+                /// raised to a negative power. 
+                fn pow(self, rhs: $exponent) -> Self::Output
+                {
+                    Self::pow(self, rhs.into())
+                }
+            }
+        )*
+    };
+}
+
+pow_signed!(GenericFraction<T>, i8, i16, i32, u8, u16);
+pow_signed!(GenericComplex<GenericFraction<T>>, i8, i16, i32, u8, u16);
+
+/// Implements `Pow<u32>`, where exponents above `i32::MAX` are squared from half of the
+/// exponent. 
+macro_rules! pow_u32
+{
+    ($type:ty) =>
+    {
+        impl<T: SignedInteger> Pow<u32> for $type
+        {
+            type Output = $type;
+
+            /// Raises the value to the power of `rhs`, panicking if it overflows. 
+            fn pow(self, rhs: u32) -> Self::Output
+            {
+                match i32::try_from(rhs)
+                {
+                    Ok(exponent) => Self::pow(self, exponent),
+                    Err(_) =>
+                    {
+                        let half = Self::pow(self, (rhs / 2) as i32);
+
+                        half * half * Self::pow(self, (rhs % 2) as i32)
+                    }
+                }
+            }
+        }
+    };
+}
+
+pow_u32!(GenericFraction<T>);
+pow_u32!(GenericComplex<GenericFraction<T>>);
+
+impl<T: SignedInteger> Zero for GenericComplex<GenericFraction<T>>
+{
+    fn zero() -> Self
+    {
+        GenericComplex::ZERO
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        self.get_real().is_zero() && self.get_imaginary().is_zero()
+    }
+}
+
+impl<T: SignedInteger> ConstZero for GenericComplex<GenericFraction<T>>
+{
+    const ZERO: Self = GenericComplex::ZERO;
+}
+
+impl<T: SignedInteger> One for GenericComplex<GenericFraction<T>>
+{
+    fn one() -> Self
+    {
+        GenericComplex::ONE
+    }
+}
+
+impl<T: SignedInteger> ConstOne for GenericComplex<GenericFraction<T>>
+{
+    const ONE: Self = GenericComplex::ONE;
+}
+
+impl<T: SignedInteger> Inv for GenericComplex<GenericFraction<T>>
+{
+    type Output = GenericComplex<GenericFraction<T>>;
+
+    /// Returns the reciprocal, panicking if the complex number is 0. 
+    fn inv(self) -> Self::Output
+    {
+        GenericComplex::ONE / self
+    }
+}
+
+checked_operator!(GenericComplex<GenericFraction<T>>, CheckedAdd, checked_add);
+checked_operator!(GenericComplex<GenericFraction<T>>, CheckedSub, checked_sub);
+checked_operator!(GenericComplex<GenericFraction<T>>, CheckedMul, checked_mul);
+checked_operator!(GenericComplex<GenericFraction<T>>, CheckedDiv, checked_div);
+
+forward_operator!(GenericComplex<GenericFraction<T>>, SaturatingAdd, saturating_add);
+forward_operator!(GenericComplex<GenericFraction<T>>, SaturatingSub, saturating_sub);
+forward_operator!(GenericComplex<GenericFraction<T>>, SaturatingMul, saturating_mul);
+forward_operator!(GenericComplex<GenericFraction<T>>, WrappingAdd, wrapping_add);
+forward_operator!(GenericComplex<GenericFraction<T>>, WrappingSub, wrapping_sub);
+forward_operator!(GenericComplex<GenericFraction<T>>, WrappingMul, wrapping_mul);
+
+impl<T: SignedInteger> CheckedNeg for GenericComplex<GenericFraction<T>>
+{
+    fn checked_neg(&self) -> Option<Self>
+    {
+        GenericComplex::checked_neg(*self).ok()
+    }
+}
+
+impl<T: SignedInteger> ToPrimitive for GenericComplex<GenericFraction<T>>
+{
+    /// Converts the real component to an integer rounded towards zero, returning `None`
+    /// if the imaginary component is not 0 or the real component does not fit. 
+    fn to_i64(&self) -> Option<i64>
+    {
+        self.real_part()?.to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128>
+    {
+        self.real_part()?.to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64>
+    {
+        self.real_part()?.to_u64()
+    }
+
+    fn to_u128(&self) -> Option<u128>
+    {
+        self.real_part()?.to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32>
+    {
+        ToPrimitive::to_f32(&self.real_part()?)
+    }
+
+    fn to_f64(&self) -> Option<f64>
+    {
+        ToPrimitive::to_f64(&self.real_part()?)
+    }
+}
+
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
+{
+    /// Returns the real component if the imaginary component is 0. 
+    fn real_part(&self) -> Option<GenericFraction<T>>
+    {
+        self.get_imaginary().is_zero().then(|| self.get_real())
+    }
+}
+
+impl<T: SignedInteger> FromPrimitive for GenericComplex<GenericFraction<T>>
+{
+    fn from_i64(n: i64) -> Option<Self>
+    {
+        GenericFraction::from_i64(n).map(Self::from_fraction)
+    }
+
+    fn from_i128(n: i128) -> Option<Self>
+    {
+        GenericFraction::from_i128(n).map(Self::from_fraction)
+    }
+
+    fn from_u64(n: u64) -> Option<Self>
+    {
+        GenericFraction::from_u64(n).map(Self::from_fraction)
+    }
+
+    fn from_u128(n: u128) -> Option<Self>
+    {
+        GenericFraction::from_u128(n).map(Self::from_fraction)
+    }
+
+    fn from_f64(n: f64) -> Option<Self>
+    {
+        <GenericFraction<T> as FromPrimitive>::from_f64(n).map(Self::from_fraction)
+    }
+}
+
+impl<T: SignedInteger> NumCast for GenericComplex<GenericFraction<T>>
+{
+    fn from<N: ToPrimitive>(n: N) -> Option<Self>
+    {
+        <GenericFraction<T> as NumCast>::from(n).map(Self::from_fraction)
+    }
+}
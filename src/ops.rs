@@ -0,0 +1,150 @@
+use crate::complex::GenericComplex;
+use crate::fraction::GenericFraction;
+use crate::integer::SignedInteger;
+
+/// Implements a binary operator between `$lhs` and `$rhs` for their owned and borrowed
+/// forms by converting both operands to `$output` and using `$output op $output`. 
+/// The `borrowed` form leaves out `$lhs op $rhs`, for when it already exists. 
+macro_rules! convert_binary_operator
+{
+    ([$($generics:tt)*] $lhs:ty, $rhs:ty => $output:ty, $lhs_into:path, $rhs_into:path, $trait:ident, $method:ident) =>
+    {
+        impl<$($generics)*> std::ops::$trait<$rhs> for $lhs
+        {
+            type Output = $output;
+
+            fn $method(self, rhs: $rhs) -> Self::Output
+            {
+                std::ops::$trait::$method($lhs_into(self), $rhs_into(rhs))
+            }
+        }
+
+        convert_binary_operator!(borrowed [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into, $trait, $method);
+    };
+    (borrowed [$($generics:tt)*] $lhs:ty, $rhs:ty => $output:ty, $lhs_into:path, $rhs_into:path, $trait:ident, $method:ident) =>
+    {
+        impl<$($generics)*> std::ops::$trait<&$rhs> for $lhs
+        {
+            type Output = $output;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output
+            {
+                std::ops::$trait::$method($lhs_into(self), $rhs_into(*rhs))
+            }
+        }
+
+        impl<$($generics)*> std::ops::$trait<$rhs> for &$lhs
+        {
+            type Output = $output;
+
+            fn $method(self, rhs: $rhs) -> Self::Output
+            {
+                std::ops::$trait::$method($lhs_into(*self), $rhs_into(rhs))
+            }
+        }
+
+        impl<$($generics)*> std::ops::$trait<&$rhs> for &$lhs
+        {
+            type Output = $output;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output
+            {
+                std::ops::$trait::$method($lhs_into(*self), $rhs_into(*rhs))
+            }
+        }
+    };
+}
+
+/// Implements an assigning operator with owned and borrowed forms of `$rhs` through
+/// `$lhs op $rhs`. 
+/// The `borrowed` form leaves out the owned `$rhs`, for when it already exists. 
+macro_rules! convert_assign_operator
+{
+    ([$($generics:tt)*] $lhs:ty, $rhs:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) =>
+    {
+        impl<$($generics)*> std::ops::$assign_trait<$rhs> for $lhs
+        {
+            fn $assign_method(&mut self, rhs: $rhs)
+            {
+                *self = std::ops::$trait::$method(*self, rhs);
+            }
+        }
+
+        convert_assign_operator!(borrowed [$($generics)*] $lhs, $rhs, $trait, $method, $assign_trait, $assign_method);
+    };
+    (borrowed [$($generics:tt)*] $lhs:ty, $rhs:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) =>
+    {
+        impl<$($generics)*> std::ops::$assign_trait<&$rhs> for $lhs
+        {
+            fn $assign_method(&mut self, rhs: &$rhs)
+            {
+                *self = std::ops::$trait::$method(*self, *rhs);
+            }
+        }
+    };
+}
+
+/// Implements `Add`, `Sub`, `Mul` and `Div` between two types, along with the assigning
+/// variants when the output is `$lhs`. 
+macro_rules! convert_operators
+{
+    ($($form:ident)? [$($generics:tt)*] $lhs:ty, $rhs:ty => $output:ty, $lhs_into:path, $rhs_into:path) =>
+    {
+        convert_binary_operator!($($form)? [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into, Add, add);
+        convert_binary_operator!($($form)? [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into, Sub, sub);
+        convert_binary_operator!($($form)? [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into, Mul, mul);
+        convert_binary_operator!($($form)? [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into, Div, div);
+    };
+    ($($form:ident)? [$($generics:tt)*] $lhs:ty, $rhs:ty => $output:ty, $lhs_into:path, $rhs_into:path, assign) =>
+    {
+        convert_operators!($($form)? [$($generics)*] $lhs, $rhs => $output, $lhs_into, $rhs_into);
+
+        convert_assign_operator!($($form)? [$($generics)*] $lhs, $rhs, Add, add, AddAssign, add_assign);
+        convert_assign_operator!($($form)? [$($generics)*] $lhs, $rhs, Sub, sub, SubAssign, sub_assign);
+        convert_assign_operator!($($form)? [$($generics)*] $lhs, $rhs, Mul, mul, MulAssign, mul_assign);
+        convert_assign_operator!($($form)? [$($generics)*] $lhs, $rhs, Div, div, DivAssign, div_assign);
+    };
+}
+
+fn same<V>(value: V) -> V
+{
+    value
+}
+
+fn fraction_from_integer<T: SignedInteger>(value: T) -> GenericFraction<T>
+{
+    GenericFraction::from_integer(value)
+}
+
+fn complex_from_fraction<T: SignedInteger>(value: GenericFraction<T>) -> GenericComplex<GenericFraction<T>>
+{
+    GenericComplex::from(value, GenericFraction::ZERO)
+}
+
+fn complex_from_integer<T: SignedInteger>(value: T) -> GenericComplex<GenericFraction<T>>
+{
+    GenericComplex::from(GenericFraction::from_integer(value), GenericFraction::ZERO)
+}
+
+convert_operators!(borrowed [T: SignedInteger] GenericFraction<T>, GenericFraction<T> => GenericFraction<T>, same, same, assign);
+convert_operators!(borrowed [T: SignedInteger] GenericComplex<GenericFraction<T>>, GenericComplex<GenericFraction<T>> => GenericComplex<GenericFraction<T>>, same, same, assign);
+
+convert_operators!([T: SignedInteger] GenericFraction<T>, T => GenericFraction<T>, same, fraction_from_integer, assign);
+convert_operators!([T: SignedInteger] GenericComplex<GenericFraction<T>>, T => GenericComplex<GenericFraction<T>>, same, complex_from_integer, assign);
+convert_operators!([T: SignedInteger] GenericComplex<GenericFraction<T>>, GenericFraction<T> => GenericComplex<GenericFraction<T>>, same, complex_from_fraction, assign);
+convert_operators!([T: SignedInteger] GenericFraction<T>, GenericComplex<GenericFraction<T>> => GenericComplex<GenericFraction<T>>, complex_from_fraction, same);
+
+/// Implements the operators with a primitive integer on the left, which can not be done
+/// for every `T: SignedInteger` at once. 
+macro_rules! integer_operators
+{
+    ($($integer:ty),*) =>
+    {
+        $(
+            convert_operators!([] $integer, GenericFraction<$integer> => GenericFraction<$integer>, fraction_from_integer, same);
+            convert_operators!([] $integer, GenericComplex<GenericFraction<$integer>> => GenericComplex<GenericFraction<$integer>>, complex_from_integer, same);
+        )*
+    };
+}
+
+integer_operators!(i8, i16, i32, i64, i128);
@@ -0,0 +1,630 @@
+use crate::big_complex::BigComplex;
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::complex::ParseComplexError;
+use crate::fraction::{GenericFraction, ParseFractionError, ParseFractionErrorKind};
+
+/// The sign written between the magnitude and the angle of the polar form `r∠θ`. 
+const ANGLE: char = '∠';
+
+/// The combining overline that can be written after each digit of a repetend, as in `0.16̅`. 
+const OVERLINE: char = '\u{305}';
+
+/// A decimal number as it was written, before being converted to a fraction. 
+struct Decimal<'a>
+{
+    integer: &'a str,
+    fraction: &'a str,
+    /// May contain overlines between the digits, which are skipped when converting. 
+    repetend: &'a str,
+    exponent: i64,
+    is_integer: bool,
+}
+
+/// A parser for numbers written as fractions, mixed numbers or decimals, which keeps
+/// track of the byte position in the input for error messages. 
+pub(crate) struct Parser<'a>
+{
+    input: &'a str,
+    position: usize,
+    /// The largest exponent that is accepted beyond the length of the input, so that
+    /// huge powers of 10 are not computed for values that can not be represented. 
+    exponent_limit: u64,
+}
+
+impl<'a> Parser<'a>
+{
+    pub(crate) fn new(input: &'a str, exponent_limit: u64) -> Parser<'a>
+    {
+        Parser {input, position: 0, exponent_limit}
+    }
+
+    pub(crate) fn position(&self) -> usize
+    {
+        self.position
+    }
+
+    pub(crate) fn is_at_end(&self) -> bool
+    {
+        self.position == self.input.len()
+    }
+
+    pub(crate) fn peek(&self) -> Option<char>
+    {
+        self.input[self.position..].chars().next()
+    }
+
+    /// Advances past `expected` if it is the next character, returning whether it was. 
+    pub(crate) fn eat(&mut self, expected: char) -> bool
+    {
+        if self.peek() == Some(expected)
+        {
+            self.position += expected.len_utf8();
+            return true;
+        }
+
+        false
+    }
+
+    pub(crate) fn skip_whitespace(&mut self)
+    {
+        while let Some(character) = self.peek().filter(|character| character.is_whitespace())
+        {
+            self.position += character.len_utf8();
+        }
+    }
+
+    pub(crate) fn error(&self, kind: ParseFractionErrorKind, position: usize) -> ParseFractionError
+    {
+        ParseFractionError::new(kind, position)
+    }
+
+    /// Returns the error for the character at the current position, which was not expected. 
+    pub(crate) fn unexpected(&self) -> ParseFractionError
+    {
+        if self.is_at_end()
+        {
+            self.error(ParseFractionErrorKind::UnexpectedEnd, self.position)
+        }
+        else
+        {
+            self.error(ParseFractionErrorKind::InvalidCharacter, self.position)
+        }
+    }
+
+    /// Advances past an optional `+` or `-`, returning true if the sign was negative. 
+    pub(crate) fn sign(&mut self) -> bool
+    {
+        if self.eat('-')
+        {
+            return true;
+        }
+
+        self.eat('+');
+
+        false
+    }
+
+    fn digits(&mut self) -> &'a str
+    {
+        let start = self.position;
+
+        while self.peek().is_some_and(|character| character.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+
+        &self.input[start..self.position]
+    }
+
+    fn required_digits(&mut self) -> Result<&'a str, ParseFractionError>
+    {
+        let digits = self.digits();
+
+        if digits.is_empty()
+        {
+            return Err(self.unexpected());
+        }
+
+        Ok(digits)
+    }
+
+    /// Parses at least one digit in base `radix`. 
+    fn radix_digits(&mut self, radix: u32) -> Result<BigUint, ParseFractionError>
+    {
+        let base = BigUint::from(radix);
+        let start = self.position;
+        let mut value = BigUint::zero();
+
+        while let Some(digit) = self.peek().and_then(|character| character.to_digit(radix))
+        {
+            value = &value * &base + BigUint::from(digit);
+            self.position += 1;
+        }
+
+        if self.position == start
+        {
+            return Err(self.unexpected());
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a signed number written as `a/b`, as a mixed number `w a/b`, or as a decimal
+    /// with an optional repetend and exponent, stopping at the first character that can
+    /// not be part of the number. 
+    pub(crate) fn fraction(&mut self) -> Result<BigFraction, ParseFractionError>
+    {
+        let start = self.position;
+        let negative = self.sign();
+        let first = self.decimal()?;
+
+        if !first.is_integer
+        {
+            return self.decimal_value(negative, &first, start);
+        }
+
+        let end = self.position;
+        self.skip_whitespace();
+
+        if self.eat('/')
+        {
+            let numerator = BigInt::from_biguint(negative, digits_value(first.integer));
+
+            return self.over_denominator(numerator);
+        }
+
+        if self.position > end && self.peek().is_some_and(|character| character.is_ascii_digit())
+        {
+            let whole = BigInt::from_biguint(false, digits_value(first.integer));
+            let numerator = BigInt::from_biguint(false, digits_value(self.digits()));
+
+            self.skip_whitespace();
+
+            if !self.eat('/')
+            {
+                return Err(self.unexpected());
+            }
+
+            let fraction = BigFraction::from_integer(whole) + self.over_denominator(numerator)?;
+
+            return Ok(if negative { -fraction } else { fraction });
+        }
+
+        self.position = end;
+
+        Ok(BigFraction::from_integer(BigInt::from_biguint(negative, digits_value(first.integer))))
+    }
+
+    /// Parses the denominator after a `/`, and divides `numerator` by it. 
+    fn over_denominator(&mut self, numerator: BigInt) -> Result<BigFraction, ParseFractionError>
+    {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let denominator = digits_value(self.required_digits()?);
+
+        BigFraction::from(numerator, denominator)
+            .map_err(|_| self.error(ParseFractionErrorKind::ZeroDenominator, start))
+    }
+
+    fn decimal(&mut self) -> Result<Decimal<'a>, ParseFractionError>
+    {
+        let start = self.position;
+        let integer = self.digits();
+        let mut fraction = "";
+        let mut repetend = "";
+        let mut is_integer = true;
+
+        if self.eat('.')
+        {
+            is_integer = false;
+
+            let start = self.position;
+            let mut repetend_start = None;
+
+            while self.peek().is_some_and(|character| character.is_ascii_digit())
+            {
+                let digit_position = self.position;
+                self.position += 1;
+
+                if self.eat(OVERLINE)
+                {
+                    repetend_start.get_or_insert(digit_position);
+                }
+                else if repetend_start.is_some()
+                {
+                    // every digit after the first overlined digit must be overlined too
+                    return Err(self.error(ParseFractionErrorKind::InvalidCharacter, digit_position));
+                }
+            }
+
+            fraction = &self.input[start..repetend_start.unwrap_or(self.position)];
+
+            if let Some(repetend_start) = repetend_start
+            {
+                repetend = &self.input[repetend_start..self.position];
+            }
+            else if self.eat('(')
+            {
+                repetend = self.required_digits()?;
+
+                if !self.eat(')')
+                {
+                    return Err(self.unexpected());
+                }
+            }
+        }
+
+        if integer.is_empty() && fraction.is_empty() && repetend.is_empty()
+        {
+            self.position = start;
+
+            return Err(self.unexpected());
+        }
+
+        let mut exponent = 0;
+
+        if self.eat('e') || self.eat('E')
+        {
+            is_integer = false;
+
+            let exponent_negative = self.sign();
+            let magnitude = self.required_digits()?.bytes()
+                .fold(0i64, |result, byte| result.saturating_mul(10).saturating_add((byte - b'0') as i64));
+
+            exponent = if exponent_negative { -magnitude } else { magnitude };
+        }
+
+        Ok(Decimal {integer, fraction, repetend, exponent, is_integer})
+    }
+
+    /// Converts `decimal` to a fraction, returning an overflow error at `start`, where
+    /// the number begins, if its exponent is above the limit. 
+    fn decimal_value(&self, negative: bool, decimal: &Decimal, start: usize) -> Result<BigFraction, ParseFractionError>
+    {
+        let ten = BigUint::from(10u32);
+        let digits = format!("{}{}", decimal.integer, decimal.fraction);
+
+        let mut numerator = digits_value(&digits);
+        let mut denominator = ten.pow(decimal.fraction.len() as u32);
+
+        if !decimal.repetend.is_empty()
+        {
+            // 0.(r) with a repetend of length n is equal to r / (10^n - 1)
+            let repetend_length = decimal.repetend.bytes().filter(u8::is_ascii_digit).count();
+            let nines = &ten.pow(repetend_length as u32) - &BigUint::one();
+
+            numerator = &numerator * &nines + digits_value(decimal.repetend);
+            denominator = &denominator * &nines;
+        }
+
+        if numerator.is_zero()
+        {
+            return Ok(BigFraction::from_integer(BigInt::zero()));
+        }
+
+        let power = u32::try_from(decimal.exponent.unsigned_abs()).ok()
+            .filter(|&power| power as u64 <= self.exponent_limit.saturating_add(self.input.len() as u64))
+            .ok_or(self.error(ParseFractionErrorKind::Overflow, start))?;
+
+        if decimal.exponent > 0
+        {
+            numerator = &numerator * &ten.pow(power);
+        }
+        else
+        {
+            denominator = &denominator * &ten.pow(power);
+        }
+
+        Ok(BigFraction::from(BigInt::from_biguint(negative, numerator), denominator).expect("Denominator is not 0"))
+    }
+}
+
+/// Returns the value of the ascii digits in `digits`, skipping any other characters. 
+fn digits_value(digits: &str) -> BigUint
+{
+    let ten = BigUint::from(10u32);
+
+    digits.bytes()
+        .filter(u8::is_ascii_digit)
+        .fold(BigUint::zero(), |result, byte| &result * &ten + BigUint::from((byte - b'0') as u32))
+}
+
+/// Parses the whole of `input` as a fraction, allowing surrounding whitespace. 
+pub(crate) fn parse_fraction(input: &str, exponent_limit: u64) -> Result<BigFraction, ParseFractionError>
+{
+    let mut parser = Parser::new(input, exponent_limit);
+
+    parser.skip_whitespace();
+
+    if parser.is_at_end()
+    {
+        return Err(parser.error(ParseFractionErrorKind::Empty, parser.position()));
+    }
+
+    let value = parser.fraction()?;
+
+    parser.skip_whitespace();
+
+    if !parser.is_at_end()
+    {
+        return Err(parser.unexpected());
+    }
+
+    Ok(value)
+}
+
+/// Parses the whole of `input` as an integer or as `a/b` with digits in base `radix`,
+/// without any whitespace, like the `from_str_radix` functions of the integer types. 
+pub(crate) fn parse_fraction_radix(input: &str, radix: u32) -> Result<BigFraction, ParseFractionError>
+{
+    let mut parser = Parser::new(input, 0);
+
+    if parser.is_at_end()
+    {
+        return Err(parser.error(ParseFractionErrorKind::Empty, 0));
+    }
+
+    let negative = parser.sign();
+    let numerator = BigInt::from_biguint(negative, parser.radix_digits(radix)?);
+
+    if parser.is_at_end()
+    {
+        return Ok(BigFraction::from_integer(numerator));
+    }
+
+    if !parser.eat('/')
+    {
+        return Err(parser.unexpected());
+    }
+
+    let start = parser.position();
+    let denominator = parser.radix_digits(radix)?;
+
+    if !parser.is_at_end()
+    {
+        return Err(parser.unexpected());
+    }
+
+    BigFraction::from(numerator, denominator).map_err(|_| parser.error(ParseFractionErrorKind::ZeroDenominator, start))
+}
+
+/// A complex number as it was parsed, before being converted to a fixed width type. 
+pub(crate) struct ParsedComplex
+{
+    pub(crate) value: BigComplex,
+    /// Whether the value is exact, which polar forms are not unless their angle is a
+    /// multiple of a right angle, since their components are found through `f64`. 
+    pub(crate) exact: bool,
+    /// The byte positions where the real and the imaginary component start, which is
+    /// the start of the whole number for polar forms and for components that are left out. 
+    pub(crate) positions: [usize; 2],
+}
+
+/// Parses the whole of `input` as a complex number, written either as a sum such as
+/// `3/4 - 2/5i` where either component can be left out, as a tuple `(a, b)`, or in polar
+/// form as `r∠θ`. 
+pub(crate) fn parse_complex(input: &str, exponent_limit: u64) -> Result<ParsedComplex, ParseComplexError>
+{
+    let mut parser = Parser::new(input, exponent_limit);
+
+    parser.skip_whitespace();
+
+    if parser.is_at_end()
+    {
+        return Err(ParseComplexError::Empty);
+    }
+
+    let start = parser.position();
+
+    let parsed = if parser.eat('(')
+    {
+        let (value, positions) = complex_tuple(&mut parser)?;
+
+        ParsedComplex {value, exact: true, positions}
+    }
+    else
+    {
+        let magnitude = parser.fraction().ok().and_then(|magnitude|
+        {
+            parser.skip_whitespace();
+            parser.eat(ANGLE).then_some(magnitude)
+        });
+
+        match magnitude
+        {
+            Some(magnitude) =>
+            {
+                let (value, exact) = complex_polar(&mut parser, &magnitude)?;
+
+                ParsedComplex {value, exact, positions: [start; 2]}
+            }
+            None =>
+            {
+                parser.position = start;
+
+                let (value, positions) = complex_sum(&mut parser)?;
+
+                ParsedComplex {value, exact: true, positions}
+            }
+        }
+    };
+
+    parser.skip_whitespace();
+
+    if !parser.is_at_end()
+    {
+        return Err(unexpected(&parser));
+    }
+
+    Ok(parsed)
+}
+
+/// Parses the angle of a polar form `r∠θ` after the `∠`, which is in radians, or in
+/// degrees when followed by `°`, returning the value and whether it is exact. 
+fn complex_polar(parser: &mut Parser, magnitude: &BigFraction) -> Result<(BigComplex, bool), ParseComplexError>
+{
+    parser.skip_whitespace();
+
+    let position = parser.position();
+    let angle = parser.fraction()?;
+    let degrees = parser.eat('°');
+
+    let quarter_turns = if degrees { Some(&angle / &BigFraction::from_integer(BigInt::from(90))) } else { angle.get_numerator().is_zero().then(|| angle.clone()) };
+
+    if let Some(quarter_turns) = quarter_turns.filter(|quarter_turns| *quarter_turns.get_denominator() == BigUint::one())
+    {
+        let zero = BigFraction::from_integer(BigInt::zero());
+        let turn = (quarter_turns.get_numerator() % &BigInt::from(4)).to_i128().expect("Remainder is below 4");
+
+        let value = match turn.rem_euclid(4)
+        {
+            0 => BigComplex::from(magnitude.clone(), zero),
+            1 => BigComplex::from(zero, magnitude.clone()),
+            2 => BigComplex::from(-magnitude, zero),
+            _ => BigComplex::from(zero, -magnitude),
+        };
+
+        return Ok((value, true));
+    }
+
+    let overflow = ParseComplexError::Overflow {position};
+
+    let radians = GenericFraction::<i128>::from_big_limited(&angle, u128::MAX).ok_or(overflow)?.to_f64();
+    let radians = if degrees { radians.to_radians() } else { radians };
+
+    let real = BigFraction::try_from(radians.cos()).map_err(|_| overflow)?;
+    let imaginary = BigFraction::try_from(radians.sin()).map_err(|_| overflow)?;
+
+    Ok((BigComplex::from(magnitude * &real, magnitude * &imaginary), false))
+}
+
+/// Parses the rest of a tuple `(a, b)` after its opening parenthesis, returning the
+/// value and the positions where its components start. 
+fn complex_tuple(parser: &mut Parser) -> Result<(BigComplex, [usize; 2]), ParseComplexError>
+{
+    parser.skip_whitespace();
+
+    let real_position = parser.position();
+    let real = parser.fraction()?;
+
+    parser.skip_whitespace();
+
+    if !parser.eat(',')
+    {
+        return Err(unexpected(parser));
+    }
+
+    parser.skip_whitespace();
+
+    let imaginary_position = parser.position();
+    let imaginary = parser.fraction()?;
+
+    parser.skip_whitespace();
+
+    if !parser.eat(')')
+    {
+        return Err(unexpected(parser));
+    }
+
+    Ok((BigComplex::from(real, imaginary), [real_position, imaginary_position]))
+}
+
+/// Parses one or two terms joined by `+` or `-`, with at most one real and one imaginary
+/// term, returning the value and the positions where its terms start. 
+fn complex_sum(parser: &mut Parser) -> Result<(BigComplex, [usize; 2]), ParseComplexError>
+{
+    let mut real = None;
+    let mut imaginary = None;
+    let mut positions = [parser.position(); 2];
+    let mut negative = false;
+
+    loop
+    {
+        let position = parser.position();
+        let (mut value, is_imaginary) = complex_term(parser)?;
+
+        if negative
+        {
+            value = -value;
+        }
+
+        let component = if is_imaginary { &mut imaginary } else { &mut real };
+
+        if component.replace(value).is_some()
+        {
+            return Err(ParseComplexError::RepeatedComponent {position});
+        }
+
+        positions[is_imaginary as usize] = position;
+
+        if real.is_some() && imaginary.is_some()
+        {
+            break;
+        }
+
+        let end = parser.position();
+        parser.skip_whitespace();
+
+        if parser.eat('+')
+        {
+            negative = false;
+        }
+        else if parser.eat('-')
+        {
+            negative = true;
+        }
+        else
+        {
+            parser.position = end;
+            break;
+        }
+
+        parser.skip_whitespace();
+    }
+
+    let zero = || BigFraction::from_integer(BigInt::zero());
+
+    Ok((BigComplex::from(real.unwrap_or_else(zero), imaginary.unwrap_or_else(zero)), positions))
+}
+
+/// Parses a single signed term, returning its value and whether it ended with the
+/// imaginary unit `i` or `j`. The value can be left out of imaginary terms, as in `-i`. 
+fn complex_term(parser: &mut Parser) -> Result<(BigFraction, bool), ParseComplexError>
+{
+    let start = parser.position();
+    let negative = parser.sign();
+
+    if eat_imaginary_unit(parser)
+    {
+        let one = BigFraction::from_integer(BigInt::one());
+
+        return Ok((if negative { -one } else { one }, true));
+    }
+
+    parser.position = start;
+
+    let value = parser.fraction()?;
+
+    Ok((value, eat_imaginary_unit(parser)))
+}
+
+fn eat_imaginary_unit(parser: &mut Parser) -> bool
+{
+    parser.eat('i') || parser.eat('j')
+}
+
+fn unexpected(parser: &Parser) -> ParseComplexError
+{
+    let position = parser.position();
+
+    if parser.is_at_end()
+    {
+        ParseComplexError::UnexpectedEnd {position}
+    }
+    else
+    {
+        ParseComplexError::InvalidCharacter {position}
+    }
+}
@@ -0,0 +1,252 @@
+use std::cmp::Ordering;
+
+use crate::big_fraction::BigFraction;
+use crate::big_integer::{BigInt, BigUint};
+use crate::complex::GenericComplex;
+use crate::fraction::{ArithmeticError, GenericFraction};
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// Decides which way to round when a value falls between the two closest values that
+/// can be represented. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode
+{
+    /// Rounds to the nearest value, with ties rounded away from zero. 
+    HalfUp,
+    /// Rounds to the nearest value, with ties rounded to the value with an even last
+    /// digit, which avoids any bias when many values are rounded. 
+    #[default]
+    HalfEven,
+    /// Rounds towards negative infinity. 
+    Floor,
+    /// Rounds towards positive infinity. 
+    Ceil,
+    /// Rounds towards zero, which truncates the value. 
+    TowardZero,
+    /// Rounds away from zero. 
+    AwayFromZero,
+}
+
+impl RoundingMode
+{
+    /// Returns true if a value whose magnitude was truncated should be rounded away from
+    /// zero instead, where `remainder / divisor` is the part that was cut off, and is
+    /// always less than 1. 
+    pub(crate) fn rounds_away(self, negative: bool, truncated_is_odd: bool, remainder: u128, divisor: u128) -> bool
+    {
+        // `remainder < divisor`, so this can not overflow
+        let half = (remainder != 0).then(|| remainder.cmp(&(divisor - remainder)));
+
+        self.rounds_away_from(negative, truncated_is_odd, half)
+    }
+
+    /// Returns true if a value whose magnitude was truncated should be rounded away from
+    /// zero instead, where `half` compares the part that was cut off with one half, or is
+    /// `None` if nothing was cut off. 
+    pub(crate) fn rounds_away_from(self, negative: bool, truncated_is_odd: bool, half: Option<Ordering>) -> bool
+    {
+        let Some(half) = half else { return false };
+
+        match self
+        {
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfEven => half == Ordering::Greater || half == Ordering::Equal && truncated_is_odd,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceil => !negative,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+        }
+    }
+}
+
+/// Rounds `value` to an integer with `mode`. 
+pub(crate) fn round_big(value: &BigFraction, mode: RoundingMode) -> BigInt
+{
+    let (numerator, denominator) = value.get_components();
+    let (mut whole, remainder) = numerator.magnitude().div_rem(denominator);
+
+    // compares remainder / denominator with 1/2
+    let half = (!remainder.is_zero()).then(|| (&remainder + &remainder).cmp(denominator));
+    let truncated_is_odd = !(&whole % &BigUint::from(2u32)).is_zero();
+
+    if mode.rounds_away_from(numerator.is_negative(), truncated_is_odd, half)
+    {
+        whole = &whole + &BigUint::one();
+    }
+
+    BigInt::from_biguint(numerator.is_negative(), whole)
+}
+
+/// Rounds `value` to a multiple of `step`, which must be positive, returning the
+/// multiple as a simplified fraction. 
+fn round_big_to_multiple(value: &BigFraction, step: &BigFraction, mode: RoundingMode) -> BigFraction
+{
+    &BigFraction::from_integer(round_big(&(value / step), mode)) * step
+}
+
+impl<T: SignedInteger> GenericFraction<T>
+{
+    /// Rounds the fraction to the nearest multiple of `step` with `mode`, where the sign
+    /// of `step` is ignored. 
+    /// Returns `ArithmeticError::DivByZero` if `step` is 0 or a denominator is 0, and
+    /// `ArithmeticError::Overflow` if the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, RoundingMode};
+    /// 
+    /// let fraction = Fraction::unchecked_from(7, 10);
+    /// let quarter = Fraction::unchecked_from(1, 4);
+    /// 
+    /// assert_eq!(fraction.round_to_multiple(quarter, RoundingMode::HalfEven), Ok(Fraction::unchecked_from(3, 4)));
+    /// assert_eq!(fraction.round_to_multiple(quarter, RoundingMode::Floor), Ok(Fraction::unchecked_from(1, 2)));
+    /// assert_eq!((-fraction).round_to_multiple(quarter, RoundingMode::TowardZero), Ok(Fraction::unchecked_from(-1, 2)));
+    /// ```
+    pub fn round_to_multiple(self, step: GenericFraction<T>, mode: RoundingMode) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if step.get_numerator() == T::ZERO || step.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let step: BigFraction = step.into();
+
+        self.round_to_big_multiple(&step.abs(), mode)
+    }
+
+    /// Rounds the fraction to the nearest fraction with `denominator` as its denominator
+    /// before simplifying, such as the nearest 1/16, with `mode`. 
+    /// Returns `ArithmeticError::DivByZero` if either denominator is 0, and
+    /// `ArithmeticError::Overflow` if the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, RoundingMode};
+    /// 
+    /// let fraction = Fraction::unchecked_from(1, 3);
+    /// 
+    /// assert_eq!(fraction.round_to_denominator(16, RoundingMode::HalfEven), Ok(Fraction::unchecked_from(5, 16)));
+    /// assert_eq!(fraction.round_to_denominator(16, RoundingMode::Ceil), Ok(Fraction::unchecked_from(3, 8)));
+    /// assert_eq!(Fraction::unchecked_from(3, 8).round_to_denominator(4, RoundingMode::HalfEven), Ok(Fraction::unchecked_from(1, 2)));
+    /// ```
+    pub fn round_to_denominator(self, denominator: T::Unsigned, mode: RoundingMode) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if denominator == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let step = BigFraction::from(BigInt::one(), BigUint::from(denominator.into())).expect("Denominator is not 0");
+
+        self.round_to_big_multiple(&step, mode)
+    }
+
+    /// Rounds the fraction to `places` decimal places with `mode`, such as to the nearest
+    /// cent with 2 places. 
+    /// Returns `ArithmeticError::DivByZero` if the denominator is 0, and
+    /// `ArithmeticError::Overflow` if the result can not be represented. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, RoundingMode};
+    /// 
+    /// let fraction = Fraction::unchecked_from(2, 3);
+    /// 
+    /// assert_eq!(fraction.round_decimal(2, RoundingMode::HalfUp), Ok(Fraction::unchecked_from(67, 100)));
+    /// assert_eq!(fraction.round_decimal(0, RoundingMode::HalfUp), Ok(Fraction::from_i32(1)));
+    /// assert_eq!(Fraction::unchecked_from(1, 8).round_decimal(2, RoundingMode::HalfEven), Ok(Fraction::unchecked_from(3, 25)));
+    /// ```
+    pub fn round_decimal(self, places: u32, mode: RoundingMode) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        // Two different fractions that fit are always more than 10^-80 apart, so with more
+        // places the result either is the value itself, or does not fit.
+        if places > 80 && self.get_denominator() != T::Unsigned::ZERO
+        {
+            let mut denominator: u128 = self.simplify().get_denominator().into();
+            let mut twos_and_fives = [0u32; 2];
+
+            for (factor, count) in [2, 5].into_iter().zip(&mut twos_and_fives)
+            {
+                while denominator.is_multiple_of(factor)
+                {
+                    denominator /= factor;
+                    *count += 1;
+                }
+            }
+
+            let exact = denominator == 1 && twos_and_fives.iter().all(|&count| count <= places);
+
+            return if exact { Ok(self.simplify()) } else { Err(ArithmeticError::Overflow) };
+        }
+
+        let step = BigFraction::from(BigInt::one(), BigUint::from(10u32).pow(places)).expect("Denominator is not 0");
+
+        self.round_to_big_multiple(&step, mode)
+    }
+
+    fn round_to_big_multiple(self, step: &BigFraction, mode: RoundingMode) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        if self.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ArithmeticError::DivByZero);
+        }
+
+        let rounded = round_big_to_multiple(&self.into(), step, mode);
+
+        GenericFraction::try_from(rounded).map_err(|_| ArithmeticError::Overflow)
+    }
+}
+
+impl<T: SignedInteger> GenericComplex<GenericFraction<T>>
+{
+    /// Rounds both components to the nearest multiple of `step` with `mode`, see
+    /// `GenericFraction::round_to_multiple`. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction, RoundingMode};
+    /// 
+    /// let complex = Complex::from(Fraction::unchecked_from(7, 10), Fraction::unchecked_from(-1, 3));
+    /// let rounded = complex.round_to_multiple(Fraction::unchecked_from(1, 4), RoundingMode::HalfEven);
+    /// 
+    /// assert_eq!(rounded, Ok(Complex::from(Fraction::unchecked_from(3, 4), Fraction::unchecked_from(-1, 4))));
+    /// ```
+    pub fn round_to_multiple(self, step: GenericFraction<T>, mode: RoundingMode) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        let (real, imaginary) = self.get_components();
+
+        Ok(GenericComplex::from(real.round_to_multiple(step, mode)?, imaginary.round_to_multiple(step, mode)?))
+    }
+
+    /// Rounds both components to the nearest fraction with `denominator` as its
+    /// denominator before simplifying, see `GenericFraction::round_to_denominator`. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction, RoundingMode};
+    /// 
+    /// let complex = Complex::from(Fraction::unchecked_from(1, 3), Fraction::unchecked_from(5, 7));
+    /// let rounded = complex.round_to_denominator(4, RoundingMode::Floor);
+    /// 
+    /// assert_eq!(rounded, Ok(Complex::from(Fraction::unchecked_from(1, 4), Fraction::unchecked_from(1, 2))));
+    /// ```
+    pub fn round_to_denominator(self, denominator: T::Unsigned, mode: RoundingMode) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        let (real, imaginary) = self.get_components();
+
+        Ok(GenericComplex::from(real.round_to_denominator(denominator, mode)?, imaginary.round_to_denominator(denominator, mode)?))
+    }
+
+    /// Rounds both components to `places` decimal places with `mode`, see
+    /// `GenericFraction::round_decimal`. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction, RoundingMode};
+    /// 
+    /// let complex = Complex::from(Fraction::unchecked_from(2, 3), Fraction::unchecked_from(-1, 8));
+    /// let rounded = complex.round_decimal(1, RoundingMode::HalfEven);
+    /// 
+    /// assert_eq!(rounded, Ok(Complex::from(Fraction::unchecked_from(7, 10), Fraction::unchecked_from(-1, 10))));
+    /// ```
+    pub fn round_decimal(self, places: u32, mode: RoundingMode) -> Result<GenericComplex<GenericFraction<T>>, ArithmeticError>
+    {
+        let (real, imaginary) = self.get_components();
+
+        Ok(GenericComplex::from(real.round_decimal(places, mode)?, imaginary.round_decimal(places, mode)?))
+    }
+}
@@ -0,0 +1,541 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::{self, SerializeStruct, SerializeTuple, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use crate::complex::GenericComplex;
+use crate::fraction::GenericFraction;
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+use self::sealed::{Form, Representation};
+
+const ZERO_DENOMINATOR: &str = "fraction has 0 for its denominator";
+
+const FRACTION_FIELDS: &[&str] = &["num", "den"];
+const COMPLEX_FIELDS: &[&str] = &["re", "im"];
+
+mod sealed
+{
+    use ::serde::{Deserializer, Serializer};
+
+    /// The ways that a value can be written. 
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Form
+    {
+        /// A string for human readable formats, and a tuple for the others. 
+        Default,
+        String,
+        Tuple,
+        Map,
+    }
+
+    /// The types that can be written in any `Form`, which are fractions and complex numbers. 
+    pub trait Representation: Sized
+    {
+        fn serialize_as<S: Serializer>(&self, form: Form, serializer: S) -> Result<S::Ok, S::Error>;
+
+        fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D, form: Form) -> Result<Self, D::Error>;
+    }
+}
+
+/// Writes a value in the given form, for the components of a complex number. 
+struct Represented<'a, V>(&'a V, Form);
+
+impl<V: Representation> Serialize for Represented<'_, V>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        self.0.serialize_as(self.1, serializer)
+    }
+}
+
+/// Reads a value in the given form, for the components of a complex number. 
+struct FormSeed<V>(Form, PhantomData<V>);
+
+impl<'de, V: Representation> DeserializeSeed<'de> for FormSeed<V>
+{
+    type Value = V;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<V, D::Error>
+    {
+        V::deserialize_as(deserializer, self.0)
+    }
+}
+
+impl<T> Representation for GenericFraction<T>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    fn serialize_as<S: Serializer>(&self, form: Form, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        if self.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ser::Error::custom(ZERO_DENOMINATOR));
+        }
+
+        let simplified = self.simplify();
+        let (numerator, denominator) = simplified.get_components();
+
+        match form
+        {
+            Form::Default if serializer.is_human_readable() => serializer.collect_str(&simplified),
+            Form::String => serializer.collect_str(&simplified),
+            Form::Default | Form::Tuple =>
+            {
+                let mut tuple = serializer.serialize_tuple(2)?;
+
+                tuple.serialize_element(&numerator)?;
+                tuple.serialize_element(&denominator)?;
+                tuple.end()
+            }
+            Form::Map =>
+            {
+                let mut fields = serializer.serialize_struct("Fraction", 2)?;
+
+                fields.serialize_field("num", &numerator)?;
+                fields.serialize_field("den", &denominator)?;
+                fields.end()
+            }
+        }
+    }
+
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D, form: Form) -> Result<Self, D::Error>
+    {
+        let visitor = FractionVisitor(PhantomData);
+
+        match form
+        {
+            Form::Default if deserializer.is_human_readable() => deserializer.deserialize_any(visitor),
+            Form::String => deserializer.deserialize_str(visitor),
+            Form::Default | Form::Tuple => deserializer.deserialize_tuple(2, visitor),
+            Form::Map => deserializer.deserialize_struct("Fraction", FRACTION_FIELDS, visitor),
+        }
+    }
+}
+
+/// Reads a fraction from a string, a pair, a map or an integer, whichever the data has. 
+struct FractionVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FractionVisitor<T>
+where
+    T: SignedInteger + DeserializeOwned,
+    T::Unsigned: DeserializeOwned,
+{
+    type Value = GenericFraction<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "a fraction as a string, an integer, a [numerator, denominator] pair or a map with `num` and `den`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E>
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E>
+    {
+        self.visit_i128(value.into())
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E>
+    {
+        let integer = T::try_from(value).map_err(|_| E::invalid_value(Unexpected::Other("integer out of range"), &self))?;
+
+        Ok(GenericFraction::from_integer(integer))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E>
+    {
+        self.visit_i128(value.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E>
+    {
+        let value = i128::try_from(value).map_err(|_| E::invalid_value(Unexpected::Other("integer out of range"), &self))?;
+
+        self.visit_i128(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    {
+        let numerator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let denominator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        GenericFraction::from(numerator, denominator).map_err(|_| de::Error::custom(ZERO_DENOMINATOR))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error>
+    {
+        let (mut numerator, mut denominator) = (None, None);
+
+        while let Some(key) = map.next_key::<String>()?
+        {
+            match key.as_str()
+            {
+                "num" if numerator.is_none() => numerator = Some(map.next_value()?),
+                "den" if denominator.is_none() => denominator = Some(map.next_value()?),
+                "num" | "den" => return Err(de::Error::custom(format!("duplicate field `{key}`"))),
+                _ => return Err(de::Error::unknown_field(&key, FRACTION_FIELDS)),
+            }
+        }
+
+        let numerator = numerator.ok_or_else(|| de::Error::missing_field("num"))?;
+        let denominator = denominator.ok_or_else(|| de::Error::missing_field("den"))?;
+
+        GenericFraction::from(numerator, denominator).map_err(|_| de::Error::custom(ZERO_DENOMINATOR))
+    }
+}
+
+impl<T> Representation for GenericComplex<GenericFraction<T>>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    fn serialize_as<S: Serializer>(&self, form: Form, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        let (real, imaginary) = self.get_components();
+
+        if real.get_denominator() == T::Unsigned::ZERO || imaginary.get_denominator() == T::Unsigned::ZERO
+        {
+            return Err(ser::Error::custom(ZERO_DENOMINATOR));
+        }
+
+        let (real, imaginary) = (real.simplify(), imaginary.simplify());
+        let simplified = GenericComplex::from(real, imaginary);
+
+        match form
+        {
+            Form::Default if serializer.is_human_readable() => serializer.collect_str(&simplified),
+            Form::String => serializer.collect_str(&simplified),
+            Form::Default | Form::Tuple =>
+            {
+                let mut tuple = serializer.serialize_tuple(2)?;
+
+                tuple.serialize_element(&Represented(&real, form))?;
+                tuple.serialize_element(&Represented(&imaginary, form))?;
+                tuple.end()
+            }
+            Form::Map =>
+            {
+                let mut fields = serializer.serialize_struct("Complex", 2)?;
+
+                fields.serialize_field("re", &Represented(&real, form))?;
+                fields.serialize_field("im", &Represented(&imaginary, form))?;
+                fields.end()
+            }
+        }
+    }
+
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D, form: Form) -> Result<Self, D::Error>
+    {
+        let visitor = ComplexVisitor(form, PhantomData);
+
+        match form
+        {
+            Form::Default if deserializer.is_human_readable() => deserializer.deserialize_any(visitor),
+            Form::String => deserializer.deserialize_str(visitor),
+            Form::Default | Form::Tuple => deserializer.deserialize_tuple(2, visitor),
+            Form::Map => deserializer.deserialize_struct("Complex", COMPLEX_FIELDS, visitor),
+        }
+    }
+}
+
+/// Reads a complex number from a string, a pair, a map or an integer, whichever the data
+/// has, with the components in the given form. 
+struct ComplexVisitor<T>(Form, PhantomData<T>);
+
+impl<T> ComplexVisitor<T>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    fn component(&self) -> FormSeed<GenericFraction<T>>
+    {
+        FormSeed(self.0, PhantomData)
+    }
+}
+
+impl<'de, T> Visitor<'de> for ComplexVisitor<T>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    type Value = GenericComplex<GenericFraction<T>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "a complex number as a string, an integer, a [real, imaginary] pair or a map with `re` and `im`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E>
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E>
+    {
+        FractionVisitor(PhantomData).visit_i64(value).map(GenericComplex::<GenericFraction<T>>::from_fraction)
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E>
+    {
+        FractionVisitor(PhantomData).visit_i128(value).map(GenericComplex::<GenericFraction<T>>::from_fraction)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E>
+    {
+        FractionVisitor(PhantomData).visit_u64(value).map(GenericComplex::<GenericFraction<T>>::from_fraction)
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E>
+    {
+        FractionVisitor(PhantomData).visit_u128(value).map(GenericComplex::<GenericFraction<T>>::from_fraction)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    {
+        let real = seq.next_element_seed(self.component())?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let imaginary = seq.next_element_seed(self.component())?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok(GenericComplex::from(real, imaginary))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error>
+    {
+        let (mut real, mut imaginary) = (None, None);
+
+        while let Some(key) = map.next_key::<String>()?
+        {
+            match key.as_str()
+            {
+                "re" if real.is_none() => real = Some(map.next_value_seed(self.component())?),
+                "im" if imaginary.is_none() => imaginary = Some(map.next_value_seed(self.component())?),
+                "re" | "im" => return Err(de::Error::custom(format!("duplicate field `{key}`"))),
+                _ => return Err(de::Error::unknown_field(&key, COMPLEX_FIELDS)),
+            }
+        }
+
+        let real = real.ok_or_else(|| de::Error::missing_field("re"))?;
+        let imaginary = imaginary.ok_or_else(|| de::Error::missing_field("im"))?;
+
+        Ok(GenericComplex::from(real, imaginary))
+    }
+}
+
+impl<T> Serialize for GenericFraction<T>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    /// Writes the simplified fraction as a string such as `"-3/4"` in human readable
+    /// formats, and as a `(numerator, denominator)` tuple in the others. 
+    /// Returns an error if the denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// assert_eq!(serde_json::to_string(&Fraction::unchecked_from(-6, 8)).unwrap(), r#""-3/4""#);
+    /// assert!(serde_json::to_string(&Fraction::unchecked_from(1, 0)).is_err());
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        self.serialize_as(Form::Default, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for GenericFraction<T>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    /// Reads a fraction from a string, an integer, a `[numerator, denominator]` pair or a
+    /// map with `num` and `den` in human readable formats, and from a tuple in the others. 
+    /// The fraction is simplified, and a denominator of 0 is an error. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// 
+    /// let expected = Fraction::unchecked_from(-3, 4);
+    /// 
+    /// assert_eq!(serde_json::from_str::<Fraction>(r#""-3/4""#).unwrap(), expected);
+    /// assert_eq!(serde_json::from_str::<Fraction>("[-6, 8]").unwrap().get_components(), (-3, 4));
+    /// assert_eq!(serde_json::from_str::<Fraction>(r#"{"num": -3, "den": 4}"#).unwrap(), expected);
+    /// assert_eq!(serde_json::from_str::<Fraction>("5").unwrap(), Fraction::from_i32(5));
+    /// assert!(serde_json::from_str::<Fraction>("[1, 0]").is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        GenericFraction::deserialize_as(deserializer, Form::Default)
+    }
+}
+
+impl<T> Serialize for GenericComplex<GenericFraction<T>>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    /// Writes the complex number with simplified components as a string such as
+    /// `"1 - 2i"` in human readable formats, and as a tuple of the component tuples in the
+    /// others. 
+    /// Returns an error if either denominator is 0. 
+    /// 
+    /// ```
+    /// use complex::Complex;
+    /// 
+    /// assert_eq!(serde_json::to_string(&Complex::from_i32_pair(1, -2)).unwrap(), r#""1 - 2i""#);
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        self.serialize_as(Form::Default, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for GenericComplex<GenericFraction<T>>
+where
+    T: SignedInteger + Serialize + DeserializeOwned,
+    T::Unsigned: Serialize + DeserializeOwned,
+{
+    /// Reads a complex number from a string, an integer, a `[real, imaginary]` pair or a
+    /// map with `re` and `im` in human readable formats, where each component can be
+    /// written in any of the ways that a fraction can, and from a tuple in the others. 
+    /// The components are simplified, and a denominator of 0 is an error. 
+    /// 
+    /// ```
+    /// use complex::{Complex, Fraction};
+    /// 
+    /// let expected = Complex::from(Fraction::unchecked_from(1, 2), Fraction::from_i32(-2));
+    /// 
+    /// assert_eq!(serde_json::from_str::<Complex>(r#""1/2 - 2i""#).unwrap(), expected);
+    /// assert_eq!(serde_json::from_str::<Complex>(r#"["1/2", -2]"#).unwrap(), expected);
+    /// assert_eq!(serde_json::from_str::<Complex>(r#"{"re": [2, 4], "im": {"num": -2, "den": 1}}"#).unwrap(), expected);
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        GenericComplex::deserialize_as(deserializer, Form::Default)
+    }
+}
+
+/// Writes fractions as strings such as `"3/4"` and complex numbers as strings such as
+/// `"1 - 2i"`, for use with `#[serde(with = "complex::serde::string")]`. 
+/// 
+/// ```
+/// use complex::Complex;
+/// use serde::{Deserialize, Serialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct State
+/// {
+///     #[serde(with = "complex::serde::string")]
+///     position: Complex,
+/// }
+/// 
+/// let json = serde_json::to_string(&State {position: Complex::from_i32_pair(1, -2)}).unwrap();
+/// 
+/// assert_eq!(json, r#"{"position":"1 - 2i"}"#);
+/// assert!(serde_json::from_str::<State>(r#"{"position":[1, -2]}"#).is_err());
+/// ```
+pub mod string
+{
+    use ::serde::{Deserializer, Serializer};
+
+    use super::sealed::{Form, Representation};
+
+    /// Writes the value as a string. 
+    pub fn serialize<V: Representation, S: Serializer>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        value.serialize_as(Form::String, serializer)
+    }
+
+    /// Reads the value from a string. 
+    pub fn deserialize<'de, V: Representation, D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error>
+    {
+        V::deserialize_as(deserializer, Form::String)
+    }
+}
+
+/// Writes fractions as `[numerator, denominator]` pairs and complex numbers as pairs of
+/// them, for use with `#[serde(with = "complex::serde::tuple")]`. 
+/// 
+/// ```
+/// use complex::{Complex, Fraction};
+/// use serde::{Deserialize, Serialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct State
+/// {
+///     #[serde(with = "complex::serde::tuple")]
+///     ratio: Fraction,
+///     #[serde(with = "complex::serde::tuple")]
+///     position: Complex,
+/// }
+/// 
+/// let state = State {ratio: Fraction::unchecked_from(3, 4), position: Complex::from_i32_pair(1, -2)};
+/// let json = serde_json::to_string(&state).unwrap();
+/// 
+/// assert_eq!(json, r#"{"ratio":[3,4],"position":[[1,1],[-2,1]]}"#);
+/// assert_eq!(serde_json::from_str::<State>(&json).unwrap().ratio, state.ratio);
+/// ```
+pub mod tuple
+{
+    use ::serde::{Deserializer, Serializer};
+
+    use super::sealed::{Form, Representation};
+
+    /// Writes the value as a tuple. 
+    pub fn serialize<V: Representation, S: Serializer>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        value.serialize_as(Form::Tuple, serializer)
+    }
+
+    /// Reads the value from a tuple. 
+    pub fn deserialize<'de, V: Representation, D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error>
+    {
+        V::deserialize_as(deserializer, Form::Tuple)
+    }
+}
+
+/// Writes fractions as maps with `num` and `den`, and complex numbers as maps with `re`
+/// and `im` holding them, for use with `#[serde(with = "complex::serde::map")]`. 
+/// As with derived structs, a sequence of the fields in order is also accepted. 
+/// 
+/// ```
+/// use complex::{Complex, Fraction};
+/// use serde::{Deserialize, Serialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct State
+/// {
+///     #[serde(with = "complex::serde::map")]
+///     ratio: Fraction,
+///     #[serde(with = "complex::serde::map")]
+///     position: Complex,
+/// }
+/// 
+/// let state = State {ratio: Fraction::unchecked_from(3, 4), position: Complex::from_i32_pair(1, -2)};
+/// let json = serde_json::to_string(&state).unwrap();
+/// 
+/// assert_eq!(json, r#"{"ratio":{"num":3,"den":4},"position":{"re":{"num":1,"den":1},"im":{"num":-2,"den":1}}}"#);
+/// assert_eq!(serde_json::from_str::<State>(&json).unwrap().position, state.position);
+/// ```
+pub mod map
+{
+    use ::serde::{Deserializer, Serializer};
+
+    use super::sealed::{Form, Representation};
+
+    /// Writes the value as a map. 
+    pub fn serialize<V: Representation, S: Serializer>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        value.serialize_as(Form::Map, serializer)
+    }
+
+    /// Reads the value from a map. 
+    pub fn deserialize<'de, V: Representation, D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error>
+    {
+        V::deserialize_as(deserializer, Form::Map)
+    }
+}
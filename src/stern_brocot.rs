@@ -0,0 +1,385 @@
+use crate::fraction::{signed_from_magnitude, ArithmeticError, GenericFraction};
+use crate::integer::{SignedInteger, UnsignedInteger};
+
+/// A step from a node of the Stern–Brocot tree to one of its children. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction
+{
+    /// Towards the smaller child, written as `L`. 
+    Left,
+    /// Towards the larger child, written as `R`. 
+    Right,
+}
+
+/// The path from the root `1/1` of the Stern–Brocot tree to one of its nodes, which
+/// contain every positive fraction exactly once. 
+/// Paths are written as a string of `L` and `R` steps, with the root written as an empty string. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// use complex::stern_brocot::SternBrocotPath;
+/// 
+/// let path = SternBrocotPath::from_fraction(Fraction::unchecked_from(3, 7)).unwrap();
+/// 
+/// assert_eq!(path.to_string(), "LLRR");
+/// assert_eq!("LLRR".parse::<SternBrocotPath>().unwrap().to_fraction(), Ok(Fraction::unchecked_from(3, 7)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct SternBrocotPath
+{
+    /// The steps grouped into runs in the same direction, which are never empty and
+    /// never have the same direction as their neighbours. 
+    runs: Vec<(Direction, u128)>,
+}
+
+/// The error returned when parsing a `SternBrocotPath` from a string that contains a
+/// character other than `L` or `R`. 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParsePathError
+{
+    position: usize,
+}
+
+impl ParsePathError
+{
+    /// Returns the byte position of the invalid character. 
+    pub fn position(&self) -> usize
+    {
+        self.position
+    }
+}
+
+impl std::fmt::Display for ParsePathError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "invalid step in Stern–Brocot path at byte {}, expected `L` or `R`", self.position)
+    }
+}
+
+impl SternBrocotPath
+{
+    /// Returns the path to the root of the tree, which is `1/1`. 
+    pub fn root() -> SternBrocotPath
+    {
+        SternBrocotPath::default()
+    }
+
+    /// Returns the path to a positive fraction, or `None` if it is not positive or has
+    /// 0 for its denominator. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::stern_brocot::SternBrocotPath;
+    /// 
+    /// let path = SternBrocotPath::from_fraction(Fraction::unchecked_from(5, 2)).unwrap();
+    /// 
+    /// assert_eq!(path.to_string(), "RRL");
+    /// assert_eq!(SternBrocotPath::from_fraction(Fraction::from_i32(0)), None);
+    /// ```
+    pub fn from_fraction<T: SignedInteger>(value: GenericFraction<T>) -> Option<SternBrocotPath>
+    {
+        if value.get_numerator() <= T::ZERO || value.get_denominator() == T::Unsigned::ZERO
+        {
+            return None;
+        }
+
+        Some(SternBrocotPath::from_magnitude(value.get_numerator().unsigned_abs().into(), value.get_denominator().into()))
+    }
+
+    /// Returns the path to `numerator / denominator`, which must both be positive. 
+    pub(crate) fn from_magnitude(mut numerator: u128, mut denominator: u128) -> SternBrocotPath
+    {
+        // The terms of the continued fraction are the lengths of alternating runs,
+        // starting to the right, except that the last run is one step shorter.
+        let mut path = SternBrocotPath::root();
+        let mut direction = Direction::Right;
+
+        loop
+        {
+            let (term, remainder) = (numerator / denominator, numerator % denominator);
+
+            if remainder == 0
+            {
+                path.push_run(direction, term - 1);
+
+                return path;
+            }
+
+            path.push_run(direction, term);
+
+            (numerator, denominator) = (denominator, remainder);
+
+            direction = match direction
+            {
+                Direction::Left => Direction::Right,
+                Direction::Right => Direction::Left,
+            };
+        }
+    }
+
+    /// Returns the fraction at the end of the path, or `ArithmeticError::Overflow` if
+    /// it does not fit in `GenericFraction<T>`. 
+    /// 
+    /// ```
+    /// use complex::{Fraction, GenericFraction};
+    /// use complex::fraction::ArithmeticError;
+    /// use complex::stern_brocot::SternBrocotPath;
+    /// 
+    /// let path: SternBrocotPath = "RRL".parse().unwrap();
+    /// 
+    /// assert_eq!(path.to_fraction(), Ok(Fraction::unchecked_from(5, 2)));
+    /// 
+    /// let long: SternBrocotPath = "R".repeat(200).parse().unwrap();
+    /// 
+    /// assert_eq!(long.to_fraction::<i8>(), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn to_fraction<T: SignedInteger>(&self) -> Result<GenericFraction<T>, ArithmeticError>
+    {
+        // Each node is the mediant of the closest ancestors to its left and right,
+        // starting with 0/1 and 1/0.
+        let (mut left, mut right) = ((0u128, 1u128), (1u128, 0u128));
+
+        let step = |bound: (u128, u128), length: u128, other: (u128, u128)|
+        {
+            Some((other.0.checked_mul(length)?.checked_add(bound.0)?, other.1.checked_mul(length)?.checked_add(bound.1)?))
+        };
+
+        for &(direction, length) in &self.runs
+        {
+            match direction
+            {
+                Direction::Left => right = step(right, length, left).ok_or(ArithmeticError::Overflow)?,
+                Direction::Right => left = step(left, length, right).ok_or(ArithmeticError::Overflow)?,
+            }
+        }
+
+        let numerator = left.0.checked_add(right.0).and_then(|numerator| signed_from_magnitude(false, numerator));
+        let denominator = left.1.checked_add(right.1).and_then(|denominator| T::Unsigned::try_from(denominator).ok());
+
+        match (numerator, denominator)
+        {
+            (Some(numerator), Some(denominator)) => Ok(GenericFraction::unchecked_from(numerator, denominator)),
+            _ => Err(ArithmeticError::Overflow),
+        }
+    }
+
+    /// Returns true if this is the path to the root of the tree. 
+    pub fn is_root(&self) -> bool
+    {
+        self.runs.is_empty()
+    }
+
+    /// Returns the number of steps in the path. 
+    /// 
+    /// ```
+    /// use complex::stern_brocot::SternBrocotPath;
+    /// 
+    /// assert_eq!("LLRR".parse::<SternBrocotPath>().unwrap().depth(), 4);
+    /// ```
+    pub fn depth(&self) -> u128
+    {
+        self.runs.iter().map(|&(_, length)| length).sum()
+    }
+
+    /// Returns an iterator over the steps of the path, starting from the root. 
+    pub fn directions(&self) -> impl Iterator<Item = Direction> + '_
+    {
+        self.runs.iter().flat_map(|&(direction, length)| (0..length).map(move |_| direction))
+    }
+
+    /// Adds a step to the end of the path. 
+    pub fn push(&mut self, direction: Direction)
+    {
+        self.push_run(direction, 1);
+    }
+
+    /// Removes the last step of the path and returns it, or `None` for the root. 
+    pub fn pop(&mut self) -> Option<Direction>
+    {
+        let (direction, length) = self.runs.last_mut()?;
+        let direction = *direction;
+
+        *length -= 1;
+
+        if *length == 0
+        {
+            self.runs.pop();
+        }
+
+        Some(direction)
+    }
+
+    /// Returns the path to the parent of this node, or `None` for the root. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::stern_brocot::SternBrocotPath;
+    /// 
+    /// let path = SternBrocotPath::from_fraction(Fraction::unchecked_from(3, 5)).unwrap();
+    /// 
+    /// assert_eq!(path.parent().unwrap().to_fraction(), Ok(Fraction::unchecked_from(2, 3)));
+    /// assert_eq!(SternBrocotPath::root().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<SternBrocotPath>
+    {
+        let mut parent = self.clone();
+
+        parent.pop()?;
+
+        Some(parent)
+    }
+
+    /// Returns the path to the child of this node in the given direction. 
+    /// 
+    /// ```
+    /// use complex::Fraction;
+    /// use complex::stern_brocot::{Direction, SternBrocotPath};
+    /// 
+    /// let path = SternBrocotPath::from_fraction(Fraction::unchecked_from(2, 3)).unwrap();
+    /// 
+    /// assert_eq!(path.child(Direction::Left).to_fraction(), Ok(Fraction::unchecked_from(3, 5)));
+    /// assert_eq!(path.child(Direction::Right).to_fraction(), Ok(Fraction::unchecked_from(3, 4)));
+    /// ```
+    pub fn child(&self, direction: Direction) -> SternBrocotPath
+    {
+        let mut child = self.clone();
+
+        child.push(direction);
+
+        child
+    }
+
+    /// Returns the path to the smaller child of this node. 
+    pub fn left_child(&self) -> SternBrocotPath
+    {
+        self.child(Direction::Left)
+    }
+
+    /// Returns the path to the larger child of this node. 
+    pub fn right_child(&self) -> SternBrocotPath
+    {
+        self.child(Direction::Right)
+    }
+
+    /// Returns the steps grouped into runs in the same direction. 
+    pub(crate) fn runs(&self) -> &[(Direction, u128)]
+    {
+        &self.runs
+    }
+
+    fn push_run(&mut self, direction: Direction, length: u128)
+    {
+        if length == 0
+        {
+            return;
+        }
+
+        match self.runs.last_mut()
+        {
+            Some((last, last_length)) if *last == direction => *last_length += length,
+            _ => self.runs.push((direction, length)),
+        }
+    }
+}
+
+impl std::fmt::Display for SternBrocotPath
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for direction in self.directions()
+        {
+            match direction
+            {
+                Direction::Left => write!(f, "L")?,
+                Direction::Right => write!(f, "R")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for SternBrocotPath
+{
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let mut path = SternBrocotPath::root();
+
+        for (position, character) in s.char_indices()
+        {
+            match character
+            {
+                'L' => path.push(Direction::Left),
+                'R' => path.push(Direction::Right),
+                _ => return Err(ParsePathError {position}),
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+/// An iterator over every positive fraction exactly once, in the breadth first order of
+/// the Calkin–Wilf tree: `1, 1/2, 2, 1/3, 3/2, 2/3, 3, 1/4, ...`. 
+/// It ends at the first fraction that does not fit in `GenericFraction<T>`. 
+/// 
+/// ```
+/// use complex::Fraction;
+/// use complex::stern_brocot::CalkinWilf;
+/// 
+/// let fractions: Vec<Fraction> = CalkinWilf::new().take(7).collect();
+/// 
+/// assert_eq!(fractions, [(1, 1), (1, 2), (2, 1), (1, 3), (3, 2), (2, 3), (3, 1)].map(|(n, d)| Fraction::unchecked_from(n, d)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CalkinWilf<T: SignedInteger>
+{
+    next: Option<GenericFraction<T>>,
+}
+
+impl<T: SignedInteger> CalkinWilf<T>
+{
+    /// Creates an iterator that starts at `1`. 
+    pub fn new() -> CalkinWilf<T>
+    {
+        CalkinWilf {next: Some(GenericFraction::from_integer(T::ONE))}
+    }
+}
+
+impl<T: SignedInteger> Default for CalkinWilf<T>
+{
+    fn default() -> Self
+    {
+        CalkinWilf::new()
+    }
+}
+
+impl<T: SignedInteger> Iterator for CalkinWilf<T>
+{
+    type Item = GenericFraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let current = self.next?;
+        let (numerator, denominator): (i128, u128) = (current.get_numerator().into(), current.get_denominator().into());
+        let numerator = numerator as u128;
+
+        // The fraction after x is 1 / (2 * floor(x) - x + 1), which for n/d is
+        // d / ((2 * floor(n/d) + 1) * d - n), where the new denominator is at most n + d.
+        let next_denominator = (numerator / denominator)
+            .checked_mul(2)
+            .and_then(|floor| floor.checked_add(1)?.checked_mul(denominator))
+            .map(|product| product - numerator);
+
+        self.next = next_denominator.and_then(|next_denominator| Some(GenericFraction::unchecked_from
+        (
+            signed_from_magnitude(false, denominator)?,
+            T::Unsigned::try_from(next_denominator).ok()?
+        )));
+
+        Some(current)
+    }
+}
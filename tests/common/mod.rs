@@ -0,0 +1,39 @@
+use complex::Fraction;
+
+/// A small xorshift generator, so the tests are deterministic without needing any dependencies. 
+pub struct Generator(pub u64);
+
+impl Generator
+{
+    pub fn next(&mut self) -> u64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a fraction spread over the full `i32`/`u32` range, favouring values
+    /// close to the bounds where overflow used to happen. 
+    pub fn fraction(&mut self) -> Fraction
+    {
+        const NUMERATORS: [i32; 6] = [i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX];
+        const DENOMINATORS: [u32; 5] = [1, 2, i32::MAX as u32, u32::MAX - 1, u32::MAX];
+
+        let numerator = match self.next() % 4
+        {
+            0 => NUMERATORS[(self.next() % NUMERATORS.len() as u64) as usize],
+            1 => (self.next() % 1000) as i32 - 500,
+            _ => self.next() as i32,
+        };
+
+        let denominator = match self.next() % 4
+        {
+            0 => DENOMINATORS[(self.next() % DENOMINATORS.len() as u64) as usize],
+            1 => (self.next() % 1000) as u32 + 1,
+            _ => (self.next() as u32).max(1),
+        };
+
+        Fraction::unchecked_from(numerator, denominator)
+    }
+}
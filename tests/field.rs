@@ -0,0 +1,154 @@
+use complex::{Complex, ComplexField, Field, Fraction, GenericFraction, OrderedField, Ring};
+use complex::fraction::ArithmeticError;
+
+fn evaluate<R: Ring>(coefficients: &[R], x: R) -> R
+{
+    coefficients.iter().fold(R::ZERO, |total, &coefficient| total * x + coefficient)
+}
+
+fn identity<F: Ring>(size: usize) -> Vec<Vec<F>>
+{
+    (0..size).map(|row| (0..size).map(|column| if row == column { F::ONE } else { F::ZERO }).collect()).collect()
+}
+
+fn multiply<F: Ring>(lhs: &[Vec<F>], rhs: &[Vec<F>]) -> Vec<Vec<F>>
+{
+    lhs.iter()
+        .map(|row| (0..rhs[0].len()).map(|column| row.iter().zip(rhs).fold(F::ZERO, |total, (&a, b)| total + a * b[column])).collect())
+        .collect()
+}
+
+/// Inverts a matrix through Gauss-Jordan elimination, pivoting on the largest magnitude.
+fn invert<F: ComplexField>(matrix: &[Vec<F>]) -> Option<Vec<Vec<F>>>
+{
+    let size = matrix.len();
+    let mut left = matrix.to_vec();
+    let mut right = identity(size);
+
+    for column in 0..size
+    {
+        let pivot = (column..size).reduce(|a, b| if left[b][column].abs_squared() > left[a][column].abs_squared() { b } else { a })?;
+
+        left.swap(column, pivot);
+        right.swap(column, pivot);
+
+        let scale = left[column][column].reciprocal().ok()?;
+
+        for value in left[column].iter_mut().chain(right[column].iter_mut())
+        {
+            *value *= scale;
+        }
+
+        for row in (0..size).filter(|&row| row != column)
+        {
+            let factor = left[row][column];
+
+            for index in 0..size
+            {
+                let (above, below) = (left[column][index], right[column][index]);
+
+                left[row][index] -= factor * above;
+                right[row][index] -= factor * below;
+            }
+        }
+    }
+
+    Some(right)
+}
+
+fn fractions(rows: &[&[(i32, u32)]]) -> Vec<Vec<Fraction>>
+{
+    rows.iter().map(|row| row.iter().map(|&(numerator, denominator)| Fraction::unchecked_from(numerator, denominator)).collect()).collect()
+}
+
+#[test]
+fn polynomials()
+{
+    let coefficients = [Fraction::from_i32(6), Fraction::from_i32(-5), Fraction::from_i32(1)];
+
+    assert_eq!(evaluate(&coefficients, Fraction::unchecked_from(1, 2)), Fraction::ZERO);
+    assert_eq!(evaluate(&coefficients, Fraction::unchecked_from(1, 3)), Fraction::ZERO);
+    assert_eq!(evaluate(&[Complex::ONE, Complex::ZERO, Complex::from_i32(4)], Complex::from_i32_imaginary(2)), Complex::ZERO);
+    assert_eq!(evaluate(&[6.0, -5.0, 1.0], 0.5), 0.0);
+    assert_eq!(evaluate::<f32>(&[], 3.0), 0.0);
+}
+
+#[test]
+fn exact_inverse()
+{
+    let matrix = fractions(&[&[(2, 1), (1, 3), (0, 1)], &[(1, 2), (-1, 1), (4, 1)], &[(0, 1), (5, 2), (1, 7)]]);
+    let inverse = invert(&matrix).unwrap();
+
+    assert_eq!(multiply(&matrix, &inverse), identity(3));
+    assert_eq!(multiply(&inverse, &matrix), identity(3));
+    assert_eq!(invert(&inverse).unwrap(), matrix);
+
+    let singular = fractions(&[&[(1, 2), (1, 3)], &[(3, 2), (1, 1)]]);
+
+    assert_eq!(invert(&singular), None);
+}
+
+#[test]
+fn complex_inverse()
+{
+    let matrix = vec![vec![Complex::I, Complex::from_i32_pair(1, 1)], vec![Complex::from_i32(2), Complex::from_i32_pair(0, -3)]];
+    let inverse = invert(&matrix).unwrap();
+
+    assert_eq!(multiply(&matrix, &inverse), identity(2));
+    assert_eq!(invert(&[vec![Complex::I, Complex::ONE], vec![Complex::ONE, -Complex::I]]), None);
+}
+
+#[test]
+fn float_inverse()
+{
+    let matrix = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+    let inverse = invert(&matrix).unwrap();
+
+    for (row, expected) in multiply(&matrix, &inverse).iter().zip(identity::<f64>(2))
+    {
+        for (&value, expected) in row.iter().zip(expected)
+        {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    assert_eq!(invert(&[vec![1.0, 2.0], vec![2.0, 4.0]]), None);
+}
+
+#[test]
+fn components()
+{
+    let value = Complex::from_i32_pair(3, -4);
+
+    assert_eq!(value.abs_squared(), Fraction::from_i32(25));
+    assert_eq!(Complex::from_real(value.real()) + Complex::from_fraction_imaginary(value.imaginary()), value);
+    assert_eq!(ComplexField::conjugate(value), Complex::from_i32_pair(3, 4));
+    assert_eq!(Fraction::unchecked_from(-1, 2).imaginary(), Fraction::ZERO);
+    assert_eq!(ComplexField::abs_squared(-1.5f64), 2.25);
+
+    assert_eq!(Field::reciprocal(Complex::from_i32_pair(0, 2)), Ok(Complex::from(Fraction::ZERO, Fraction::unchecked_from(-1, 2))));
+    assert!(Field::reciprocal(Complex::ZERO).is_err());
+    assert!(Field::reciprocal(-0.0f64).is_err());
+
+    assert_eq!(Fraction::unchecked_from(-1, 3).magnitude(), Fraction::unchecked_from(1, 3));
+    assert_eq!(OrderedField::magnitude(-0.5f32), 0.5);
+    assert_eq!(GenericFraction::<i8>::unchecked_from(i8::MIN, 2).magnitude(), GenericFraction::from_integer(64));
+}
+
+#[test]
+#[should_panic]
+fn magnitude_above_the_maximum()
+{
+    let _ = GenericFraction::<i8>::from_integer(i8::MIN).magnitude();
+}
+
+#[test]
+fn reciprocals_above_the_maximum()
+{
+    // the denominators do not fit in the numerator of the reciprocal, unless simplified
+    assert_eq!(Field::reciprocal(Fraction::unchecked_from(1, 3_000_000_000)), Err(ArithmeticError::Overflow));
+    assert_eq!(Field::reciprocal(Fraction::unchecked_from(-2, 3_000_000_000)), Ok(Fraction::from_i32(-1_500_000_000)));
+    assert_eq!(Field::reciprocal(GenericFraction::<i8>::unchecked_from(-1, 129)), Err(ArithmeticError::Overflow));
+    assert_eq!(Field::reciprocal(GenericFraction::<i8>::unchecked_from(-1, 128)), Ok(GenericFraction::from_integer(i8::MIN)));
+    assert_eq!(Field::reciprocal(Complex::from(Fraction::unchecked_from(1, u32::MAX), Fraction::ZERO)), Err(ArithmeticError::Overflow));
+}
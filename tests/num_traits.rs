@@ -0,0 +1,155 @@
+#![cfg(feature = "num-traits")]
+
+use complex::{Complex, Fraction, GenericComplex, GenericFraction};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, FromPrimitive, Inv, Num, NumCast, One, Pow, Signed,
+    ToPrimitive, Zero,
+};
+
+fn determinant<N: Num + Copy>(matrix: [[N; 2]; 2]) -> N
+{
+    matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[1][0]
+}
+
+fn dot<N: Zero + One + Copy + std::ops::Mul<Output = N>>(lhs: &[N], rhs: &[N]) -> N
+{
+    lhs.iter().zip(rhs).fold(N::zero(), |total, (&a, &b)| total + a * b)
+}
+
+fn mean<N: Num + FromPrimitive + Copy>(values: &[N]) -> Option<N>
+{
+    let count = N::from_usize(values.len())?;
+
+    (!count.is_zero()).then(|| values.iter().fold(N::zero(), |total, &value| total + value) / count)
+}
+
+fn checked_sum_of_squares<N: CheckedAdd + CheckedMul + Zero>(values: &[N]) -> Option<N>
+{
+    values.iter().try_fold(N::zero(), |total, value| total.checked_add(&value.checked_mul(value)?))
+}
+
+fn clamp_to_bounds<N: Bounded + PartialOrd + NumCast>(value: f64) -> Option<N>
+{
+    let value = N::from(value)?;
+
+    if value < N::min_value() || value > N::max_value() { None } else { Some(value) }
+}
+
+fn distance<N: Signed + Copy>(lhs: N, rhs: N) -> N
+{
+    (lhs - rhs).abs()
+}
+
+fn power_series<N: Pow<u8, Output = N> + Zero + Copy>(value: N, terms: u8) -> N
+{
+    (0..terms).fold(N::zero(), |total, exponent| total + value.pow(exponent))
+}
+
+#[test]
+fn generic_arithmetic()
+{
+    let matrix = [[Fraction::unchecked_from(1, 2), Fraction::from_i32(3)], [Fraction::unchecked_from(-2, 3), Fraction::unchecked_from(5, 4)]];
+
+    assert_eq!(determinant(matrix), Fraction::unchecked_from(21, 8));
+
+    let lhs = [Fraction::unchecked_from(1, 2), Fraction::unchecked_from(1, 3)];
+    let rhs = [Fraction::unchecked_from(2, 5), Fraction::from_i32(-3)];
+
+    assert_eq!(dot(&lhs, &rhs), Fraction::unchecked_from(-4, 5));
+    assert_eq!(dot(&[Complex::I, Complex::I], &[Complex::I, Complex::ONE]), Complex::from_i32_pair(-1, 1));
+
+    assert_eq!(mean(&lhs), Some(Fraction::unchecked_from(5, 12)));
+    assert_eq!(mean::<Fraction>(&[]), None);
+
+    assert_eq!(power_series(Fraction::unchecked_from(1, 2), 4), Fraction::unchecked_from(15, 8));
+    assert_eq!(power_series(Complex::I, 4), Complex::ZERO);
+}
+
+#[test]
+fn checked_operations()
+{
+    assert_eq!(checked_sum_of_squares(&[Fraction::from_i32(3), Fraction::unchecked_from(1, 2)]), Some(Fraction::unchecked_from(37, 4)));
+    assert_eq!(checked_sum_of_squares(&[Fraction::from_i32(1 << 16)]), None);
+    assert_eq!(checked_sum_of_squares(&[Complex::I, Complex::from_i32(2)]), Some(Complex::from_i32(3)));
+    assert_eq!(checked_sum_of_squares(&[Complex::from_i32_pair(1 << 16, 0)]), None);
+
+    assert_eq!(CheckedNeg::checked_neg(&Fraction::from_i32(i32::MIN)), None);
+    assert_eq!(CheckedNeg::checked_neg(&Complex::from_i32_imaginary(i32::MIN)), None);
+    assert_eq!(CheckedSub::checked_sub(&Fraction::from_i32(i32::MIN), &Fraction::ONE), None);
+    assert_eq!(num_traits::CheckedDiv::checked_div(&Complex::ONE, &Complex::ZERO), None);
+    assert_eq!(num_traits::CheckedRem::checked_rem(&Fraction::unchecked_from(7, 2), &Fraction::from_i32(2)), Some(Fraction::unchecked_from(3, 2)));
+}
+
+#[test]
+fn signed()
+{
+    let (lhs, rhs) = (Fraction::unchecked_from(-3, 4), Fraction::unchecked_from(1, 2));
+
+    assert_eq!(distance(lhs, rhs), Fraction::unchecked_from(5, 4));
+    assert_eq!(Signed::signum(&lhs), Fraction::from_i32(-1));
+    assert_eq!(Signed::abs_sub(&rhs, &lhs), Fraction::unchecked_from(5, 4));
+    assert_eq!(Signed::abs_sub(&lhs, &rhs), Fraction::ZERO);
+    assert!(lhs.is_negative() && rhs.is_positive());
+    assert!(!Fraction::ZERO.is_negative() && !Fraction::ZERO.is_positive());
+}
+
+#[test]
+fn inverse_and_powers()
+{
+    assert_eq!(Fraction::unchecked_from(-2, 3).inv(), Fraction::unchecked_from(-3, 2));
+    assert_eq!(Complex::I.inv(), Complex::from_i32_imaginary(-1));
+
+    assert_eq!(Pow::pow(Fraction::unchecked_from(2, 3), -2i8), Fraction::unchecked_from(9, 4));
+    assert_eq!(Pow::pow(Complex::I, 3u16), Complex::from_i32_imaginary(-1));
+    assert_eq!(Pow::pow(Fraction::ONE, u32::MAX), Fraction::ONE);
+    assert_eq!(Pow::pow(Fraction::from_i32(-1), u32::MAX), Fraction::from_i32(-1));
+    assert_eq!(Pow::pow(Complex::I, u32::MAX), Complex::from_i32_imaginary(-1));
+}
+
+#[test]
+fn primitive_conversions()
+{
+    assert_eq!(Fraction::unchecked_from(-7, 2).to_i32(), Some(-3));
+    assert_eq!(Fraction::unchecked_from(-7, 2).to_u32(), None);
+    assert_eq!(Fraction::unchecked_from(1, 0).to_i64(), None);
+    assert_eq!(Fraction::unchecked_from(i32::MIN, 1).to_i32(), Some(i32::MIN));
+    assert_eq!(Fraction::unchecked_from(i32::MIN, 1).to_i16(), None);
+    assert_eq!(ToPrimitive::to_f64(&Fraction::unchecked_from(-3, 8)), Some(-0.375));
+    assert_eq!(Complex::from_i32(5).to_u8(), Some(5));
+    assert_eq!(Complex::I.to_f64(), None);
+
+    assert_eq!(Fraction::from_i64(-5), Some(Fraction::from_i32(-5)));
+    assert_eq!(Fraction::from_u64(u64::MAX), None);
+    assert_eq!(<Fraction as FromPrimitive>::from_f64(0.75), Some(Fraction::unchecked_from(3, 4)));
+    assert_eq!(<Fraction as FromPrimitive>::from_f64(f64::NAN), None);
+    assert_eq!(<Complex as FromPrimitive>::from_f64(-2.5), Some(Complex::from(Fraction::unchecked_from(-5, 2), Fraction::ZERO)));
+
+    assert_eq!(clamp_to_bounds::<Fraction>(-0.125), Some(Fraction::unchecked_from(-1, 8)));
+    assert_eq!(clamp_to_bounds::<Fraction>(1e10), None);
+    assert_eq!(clamp_to_bounds::<GenericFraction<i8>>(127.0), Some(GenericFraction::from_integer(127)));
+    assert_eq!(clamp_to_bounds::<GenericFraction<i8>>(1.0 / 3.0), Some(GenericFraction::unchecked_from(1, 3)));
+
+    assert_eq!(<Fraction as NumCast>::from(u128::MAX), None);
+    assert_eq!(<Fraction as NumCast>::from(i32::MAX), Some(Fraction::from_i32(i32::MAX)));
+    assert_eq!(<GenericComplex<GenericFraction<i64>> as NumCast>::from(i64::MIN), Some(GenericComplex::from_integer_pair(i64::MIN, 0)));
+    assert_eq!(<GenericFraction<i128> as NumCast>::from(1e30), Some(GenericFraction::from_integer(1e30 as i128)));
+}
+
+#[test]
+fn from_str_radix()
+{
+    assert_eq!(<Fraction as Num>::from_str_radix("-ff/10", 16), Ok(Fraction::unchecked_from(-255, 16)));
+    assert_eq!(<Fraction as Num>::from_str_radix("101/11", 2), Ok(Fraction::unchecked_from(5, 3)));
+    assert!(<Fraction as Num>::from_str_radix("1/0", 10).is_err());
+    assert!(<GenericFraction<i8> as Num>::from_str_radix("200", 10).is_err());
+    assert!(<Fraction as Num>::from_str_radix("12", 2).is_err());
+}
+
+#[test]
+fn bounds_and_identities()
+{
+    assert_eq!(GenericFraction::<i8>::min_value(), GenericFraction::from_integer(-128));
+    assert_eq!(GenericFraction::<i8>::max_value(), GenericFraction::from_integer(127));
+    assert!(Fraction::zero().is_zero() && !Fraction::unchecked_from(0, 0).is_zero());
+    assert!(Complex::one().is_one() && Complex::zero().is_zero() && !Complex::I.is_zero());
+}
@@ -0,0 +1,264 @@
+// The borrowed forms of the operators are what is being tested.
+#![allow(clippy::op_ref)]
+
+use complex::{BigFraction, BigInt, Complex, Fraction, GenericComplex, GenericFraction, SignedInteger};
+use complex::fraction::ArithmeticError;
+
+fn fraction(numerator: i32, denominator: u32) -> Fraction
+{
+    Fraction::unchecked_from(numerator, denominator)
+}
+
+fn complex(real: Fraction, imaginary: Fraction) -> Complex
+{
+    Complex::from(real, imaginary)
+}
+
+/// Evaluates `a * x^2 + b * x + c` using only borrowed operands. 
+fn quadratic<V>(a: &V, b: &V, c: &V, x: &V) -> V
+where
+    for<'a> &'a V: std::ops::Add<&'a V, Output = V> + std::ops::Mul<&'a V, Output = V>,
+{
+    let square = x * x;
+
+    &(&(a * &square) + &(b * x)) + c
+}
+
+/// Scales every value by the same integer, which has to be on the right. 
+fn scale<T: SignedInteger>(values: &mut [GenericFraction<T>], factor: T)
+{
+    for value in values
+    {
+        *value *= factor;
+    }
+}
+
+#[test]
+fn fraction_and_integer()
+{
+    let half = fraction(1, 2);
+
+    assert_eq!(half + 1, fraction(3, 2));
+    assert_eq!(half - 1, fraction(-1, 2));
+    assert_eq!(half * 3, fraction(3, 2));
+    assert_eq!(half / 3, fraction(1, 6));
+
+    assert_eq!(1 + half, fraction(3, 2));
+    assert_eq!(1 - half, fraction(1, 2));
+    assert_eq!(3 * half, fraction(3, 2));
+    assert_eq!(3 / half, Fraction::from_i32(6));
+
+    assert_eq!(&half + &2, fraction(5, 2));
+    assert_eq!(&2 - half, fraction(3, 2));
+
+    let mut value = half;
+
+    value += 2;
+    value -= &1;
+    value *= 4;
+    value /= &3;
+
+    assert_eq!(value, Fraction::from_i32(2));
+
+    let mut values = [fraction(1, 3), fraction(-5, 6)];
+
+    scale(&mut values, 6);
+
+    assert_eq!(values, [Fraction::from_i32(2), Fraction::from_i32(-5)]);
+    assert_eq!(GenericFraction::<i8>::unchecked_from(1, 2) * 4i8, GenericFraction::from_integer(2));
+    assert_eq!(4i64 * GenericFraction::<i64>::unchecked_from(1, 2), GenericFraction::from_integer(2));
+}
+
+#[test]
+fn complex_and_fraction()
+{
+    let value = complex(fraction(1, 2), fraction(-3, 4));
+    let half = fraction(1, 2);
+
+    assert_eq!(value + half, complex(Fraction::from_i32(1), fraction(-3, 4)));
+    assert_eq!(value - half, complex(Fraction::from_i32(0), fraction(-3, 4)));
+    assert_eq!(value * half, complex(fraction(1, 4), fraction(-3, 8)));
+    assert_eq!(value / half, complex(Fraction::from_i32(1), fraction(-3, 2)));
+
+    assert_eq!(half + value, value + half);
+    assert_eq!(half - value, complex(Fraction::from_i32(0), fraction(3, 4)));
+    assert_eq!(half * value, value * half);
+    assert_eq!(&half / &Complex::from_i32_pair(0, 1), complex(Fraction::from_i32(0), fraction(-1, 2)));
+
+    let mut assigned = value;
+
+    assigned *= half;
+    assigned += &half;
+
+    assert_eq!(assigned, complex(fraction(3, 4), fraction(-3, 8)));
+}
+
+#[test]
+fn complex_and_integer()
+{
+    let value = Complex::from_i32_pair(3, -4);
+
+    assert_eq!(value + 1, Complex::from_i32_pair(4, -4));
+    assert_eq!(value - 1, Complex::from_i32_pair(2, -4));
+    assert_eq!(value * 2, Complex::from_i32_pair(6, -8));
+    assert_eq!(value / 2, complex(fraction(3, 2), Fraction::from_i32(-2)));
+
+    assert_eq!(1 - value, Complex::from_i32_pair(-2, 4));
+    assert_eq!(25 / value, Complex::from_i32_pair(3, 4));
+    assert_eq!(&2 * &value, value * 2);
+
+    let mut assigned = value;
+
+    assigned -= 3;
+    assigned /= &-4;
+
+    assert_eq!(assigned, Complex::from_i32_pair(0, 1));
+    assert_eq!(2i16 * GenericComplex::<GenericFraction<i16>>::from_integer_pair(1, 1), GenericComplex::from_integer_pair(2, 2));
+}
+
+#[test]
+fn references()
+{
+    let (a, b) = (fraction(1, 2), fraction(1, 3));
+
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &b, a * b);
+    assert_eq!(&a / &b, a / b);
+
+    let mut value = a;
+
+    value -= &b;
+
+    assert_eq!(value, fraction(1, 6));
+    assert_eq!(quadratic(&fraction(1, 2), &Fraction::from_i32(-1), &fraction(1, 3), &fraction(2, 3)), fraction(-1, 9));
+
+    let (x, y) = (Complex::from_i32_pair(1, 2), Complex::from_i32_pair(0, 1));
+
+    assert_eq!(&x * &y, x * y);
+    assert_eq!(quadratic(&y, &y, &y, &y), Complex::from_i32_pair(-1, 0));
+}
+
+#[test]
+#[should_panic]
+fn integer_division_by_zero()
+{
+    let _ = fraction(1, 2) / 0;
+}
+
+/// Divides 1 by `T::MIN` i, whose conjugate can not be represented even though the
+/// exact quotient can. 
+fn divide_by_minimum_imaginary<T: SignedInteger>()
+{
+    let one = GenericComplex::from(GenericFraction::ONE, GenericFraction::ZERO);
+    let divisor = GenericComplex::from(GenericFraction::ZERO, GenericFraction::from_integer(T::MIN));
+    let quotient = GenericComplex::from(GenericFraction::ZERO, GenericFraction::unchecked_from(T::ONE, T::MIN.unsigned_abs()));
+
+    assert_eq!(one.checked_div(divisor), Ok(quotient));
+    assert_eq!(divisor.checked_div(divisor), Ok(one));
+    assert!(divisor.checked_neg().is_err());
+}
+
+#[test]
+fn minimum_components()
+{
+    divide_by_minimum_imaginary::<i8>();
+    divide_by_minimum_imaginary::<i16>();
+    divide_by_minimum_imaginary::<i32>();
+    divide_by_minimum_imaginary::<i64>();
+    divide_by_minimum_imaginary::<i128>();
+
+    let minimum = complex(fraction(i32::MIN, 1), fraction(i32::MIN, 1));
+
+    assert_eq!(complex(fraction(2, 1), fraction(0, 1)).checked_div(minimum), Ok(complex(fraction(-1, 1 << 31), fraction(1, 1 << 31))));
+    assert_eq!(complex(fraction(1, 1), fraction(0, 1)).checked_div(minimum), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn absolute_minimum()
+{
+    assert_eq!(GenericFraction::<i8>::unchecked_from(i8::MIN, 2).abs(), GenericFraction::from_integer(64));
+    assert_eq!(GenericFraction::<i8>::unchecked_from(i8::MIN, 2).checked_abs(), Ok(GenericFraction::from_integer(64)));
+    assert_eq!(GenericFraction::<i8>::from_integer(i8::MIN).checked_abs(), Err(ArithmeticError::Overflow));
+
+    assert_eq!(fraction(i32::MIN, 2).abs(), fraction(1 << 30, 1));
+    assert_eq!(fraction(i32::MIN, 2).checked_abs(), Ok(fraction(1 << 30, 1)));
+    assert_eq!(fraction(i32::MIN, 1).checked_abs(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+#[should_panic]
+fn absolute_minimum_i8()
+{
+    let _ = GenericFraction::<i8>::from_integer(i8::MIN).abs();
+}
+
+#[test]
+#[should_panic]
+fn absolute_minimum_i32()
+{
+    let _ = fraction(i32::MIN, 1).abs();
+}
+
+/// Rounds `value` down to an integer. 
+fn round_down(value: &BigFraction) -> BigInt
+{
+    let (numerator, denominator) = value.get_components();
+    let denominator = BigInt::from(denominator.clone());
+    let quotient = numerator / &denominator;
+
+    if numerator.is_negative() && &(&quotient * &denominator) != numerator { quotient - BigInt::one() } else { quotient }
+}
+
+/// Compares the integer division methods for `i8` against the exact results, for all the
+/// quotients and remainders that can be represented. 
+#[test]
+fn integer_division()
+{
+    let numerators = (i8::MIN..=i8::MAX).step_by(9).chain([i8::MIN, -1, 1, i8::MAX]);
+    let values: Vec<GenericFraction<i8>> = numerators.flat_map(|numerator| [1, 2, 3, 5, 128, 255].map(|denominator| GenericFraction::from(numerator, denominator).unwrap())).collect();
+
+    for &value in &values
+    {
+        for &divisor in values.iter().filter(|divisor| **divisor != GenericFraction::ZERO)
+        {
+            let (exact_value, exact_divisor): (BigFraction, BigFraction) = (value.into(), divisor.into());
+            let ratio = &exact_value / &exact_divisor;
+
+            let floor = round_down(&ratio);
+            let ceil = -round_down(&-&ratio);
+            let truncated = if ratio.get_numerator().is_negative() { ceil.clone() } else { floor.clone() };
+            let euclid = if divisor < GenericFraction::ZERO { ceil } else { floor.clone() };
+
+            let quotient = |quotient: &BigInt| quotient.to_i128().and_then(|quotient| i8::try_from(quotient).ok());
+            let remainder = |quotient: &BigInt| GenericFraction::<i8>::try_from(&exact_value - &(&exact_divisor * &BigFraction::from_integer(quotient.clone()))).ok();
+
+            if let Some(expected) = quotient(&floor)
+            {
+                assert_eq!(value.div_floor(divisor), expected, "{value} {divisor}");
+            }
+
+            if let Some(expected) = quotient(&euclid)
+            {
+                assert_eq!(value.div_euclid(divisor), expected, "{value} {divisor}");
+            }
+
+            if let Some(expected) = remainder(&floor)
+            {
+                assert_eq!(value.mod_floor(divisor), expected, "{value} {divisor}");
+            }
+
+            if let Some(expected) = remainder(&euclid)
+            {
+                assert_eq!(value.rem_euclid(divisor), expected, "{value} {divisor}");
+            }
+
+            assert_eq!(value.checked_rem(divisor).ok(), remainder(&truncated), "{value} {divisor}");
+
+            if let (Some(expected_quotient), Some(expected_remainder)) = (quotient(&truncated), remainder(&truncated))
+            {
+                assert_eq!(value.div_rem(divisor), (expected_quotient, expected_remainder), "{value} {divisor}");
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+
+use complex::{BigFraction, Fraction, GenericFraction};
+
+mod common;
+
+use common::Generator;
+
+fn widened_cmp(a: Fraction, b: Fraction) -> Ordering
+{
+    let (a_numerator, a_denominator) = a.get_components();
+    let (b_numerator, b_denominator) = b.get_components();
+
+    (a_numerator as i64 * b_denominator as i64).cmp(&(b_numerator as i64 * a_denominator as i64))
+}
+
+#[test]
+fn cmp_matches_widened_comparison()
+{
+    let mut generator = Generator(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..1_000_000
+    {
+        let a = generator.fraction();
+        let b = generator.fraction();
+
+        assert_eq!(a.cmp(&b), widened_cmp(a, b), "comparing {a} and {b}");
+        assert_eq!(a == b, widened_cmp(a, b) == Ordering::Equal, "comparing {a} and {b}");
+    }
+}
+
+#[test]
+fn cmp_is_antisymmetric_and_reflexive()
+{
+    let mut generator = Generator(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..100_000
+    {
+        let a = generator.fraction();
+        let b = generator.fraction();
+
+        assert_eq!(a.cmp(&b), b.cmp(&a).reverse(), "comparing {a} and {b}");
+        assert_eq!(a.cmp(&a), Ordering::Equal, "comparing {a} to itself");
+    }
+}
+
+#[test]
+fn unsimplified_fractions_are_equal_to_simplified()
+{
+    let mut generator = Generator(0xdead_beef_cafe_f00d);
+
+    for _ in 0..100_000
+    {
+        let numerator = (generator.next() % 65536) as i32 - 32768;
+        let denominator = (generator.next() % 65535) as u32 + 1;
+        let scale = (generator.next() % 32768) as i32 + 1;
+
+        let simplified = Fraction::unchecked_from(numerator, denominator);
+        let unsimplified = Fraction::unchecked_from(numerator * scale, denominator * scale as u32);
+
+        assert_eq!(simplified, unsimplified);
+    }
+}
+
+#[test]
+fn min_max_and_clamp_are_exact()
+{
+    let mut generator = Generator(0x0123_4567_89ab_cdef);
+
+    for _ in 0..100_000
+    {
+        let mut values = [generator.fraction(), generator.fraction(), generator.fraction()];
+
+        values.sort_by(|&a, &b| widened_cmp(a, b));
+
+        let [low, middle, high] = values;
+
+        assert_eq!(low.min(high).get_components(), low.get_components());
+        assert_eq!(low.max(high).get_components(), high.get_components());
+        assert_eq!(widened_cmp(middle.clamp(low, high), middle), Ordering::Equal);
+        assert_eq!(widened_cmp(high.clamp(low, middle), middle), Ordering::Equal);
+    }
+}
+
+#[test]
+fn sorting_matches_widened_comparison()
+{
+    let mut generator = Generator(0x1357_9bdf_0246_8ace);
+
+    let mut values: Vec<Fraction> = (0..10_000).map(|_| generator.fraction()).collect();
+
+    values.sort();
+
+    for pair in values.windows(2)
+    {
+        assert_ne!(widened_cmp(pair[0], pair[1]), Ordering::Greater, "{} sorted before {}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn i128_cmp_matches_exact_comparison()
+{
+    let mut generator = Generator(0x0f1e_2d3c_4b5a_6978);
+
+    for _ in 0..10_000
+    {
+        let mut fraction = ||
+        {
+            let numerator = ((generator.next() as i128) << 64 | generator.next() as i128) >> (generator.next() % 128);
+            let denominator = (((generator.next() as u128) << 64 | generator.next() as u128) >> (generator.next() % 128)).max(1);
+
+            GenericFraction::<i128>::unchecked_from(numerator, denominator)
+        };
+
+        let a = fraction();
+        let b = fraction();
+
+        let big_a: BigFraction = a.into();
+        let big_b: BigFraction = b.into();
+
+        assert_eq!(a.cmp(&b), big_a.cmp(&big_b), "comparing {a} and {b}");
+    }
+}
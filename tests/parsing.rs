@@ -0,0 +1,112 @@
+use complex::{BigFraction, Complex, Fraction, GenericComplex, GenericFraction};
+use complex::complex::ParseComplexError;
+use complex::fraction::ParseFractionErrorKind;
+
+mod common;
+
+use common::Generator;
+
+#[test]
+fn fraction_display_round_trips()
+{
+    let mut generator = Generator(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..100_000
+    {
+        let fraction = generator.fraction().simplify();
+
+        assert_eq!(fraction.to_string().parse(), Ok(fraction));
+    }
+}
+
+#[test]
+fn complex_display_round_trips()
+{
+    let mut generator = Generator(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..100_000
+    {
+        let value = Complex::from(generator.fraction().simplify(), generator.fraction().simplify());
+
+        assert_eq!(value.to_string().parse(), Ok(value), "{value}");
+    }
+}
+
+#[test]
+fn complex_display_round_trips_for_every_i8_component()
+{
+    for numerator in i8::MIN..=i8::MAX
+    {
+        for denominator in [1, 2, 127, 128, u8::MAX]
+        {
+            let component = GenericFraction::<i8>::from(numerator, denominator).unwrap();
+
+            for value in [GenericComplex::from(component, component), GenericComplex::from(GenericFraction::ZERO, component)]
+            {
+                assert_eq!(value.to_string().parse(), Ok(value), "{value}");
+            }
+        }
+    }
+}
+
+#[test]
+fn decimals_parse_to_exact_fractions()
+{
+    for numerator in -1000..=1000
+    {
+        let fraction = Fraction::from(numerator, 1000).unwrap();
+        let magnitude = numerator.unsigned_abs();
+        let sign = if numerator < 0 { "-" } else { "" };
+
+        assert_eq!(format!("{sign}{}.{:03}", magnitude / 1000, magnitude % 1000).parse(), Ok(fraction));
+        assert_eq!(format!("{numerator}e-3").parse(), Ok(fraction));
+    }
+}
+
+#[test]
+fn polar_forms()
+{
+    for (input, expected) in [("3∠0", Complex::from_i32(3)), ("3∠0°", Complex::from_i32(3)), ("3 ∠ 270°", Complex::from_i32_imaginary(-3)), ("-1/2∠-90°", Complex::from_fraction_imaginary(Fraction::unchecked_from(1, 2)))]
+    {
+        assert_eq!(input.parse(), Ok(expected), "{input}");
+    }
+
+    for degrees in (-720..=720).step_by(15)
+    {
+        let value: Complex = format!("5/2∠{degrees}°").parse().unwrap();
+        let radians = (degrees as f64).to_radians();
+
+        assert!((value.get_real().to_f64() - 2.5 * radians.cos()).abs() < 1e-9, "{degrees}");
+        assert!((value.get_imaginary().to_f64() - 2.5 * radians.sin()).abs() < 1e-9, "{degrees}");
+    }
+
+    let value: GenericComplex<GenericFraction<i8>> = "100∠0.5".parse().unwrap();
+
+    assert!((value.get_real().to_f64() - 100.0 * 0.5f64.cos()).abs() < 0.5);
+    assert!((value.get_imaginary().to_f64() - 100.0 * 0.5f64.sin()).abs() < 0.5);
+
+    assert_eq!("1000∠30°".parse::<GenericComplex<GenericFraction<i8>>>(), Err(ParseComplexError::Overflow {position: 0}));
+    assert!(matches!("2∠".parse::<Complex>(), Err(ParseComplexError::InvalidFraction(error)) if error.kind() == ParseFractionErrorKind::UnexpectedEnd && error.position() == 4));
+    assert!("∠30°".parse::<Complex>().is_err());
+    assert!("1∠30°∠30°".parse::<Complex>().is_err());
+    assert!("1 + 2i∠30°".parse::<Complex>().is_err());
+}
+
+#[test]
+fn overflow_positions()
+{
+    // the exponent limit is hit for the longer exponents, and the conversion fails for the others
+    for (input, position) in [("1e-40", 0), ("1e-400", 0), ("  -1e40", 2), ("  -1e400", 2), ("99999999999", 0)]
+    {
+        let error = input.parse::<Fraction>().unwrap_err();
+
+        assert_eq!((error.kind(), error.position()), (ParseFractionErrorKind::Overflow, position), "{input}");
+    }
+
+    assert_eq!("\t1e4000000000".parse::<BigFraction>().unwrap_err().position(), 1);
+
+    for (input, position) in [("1 + 1e-40i", 4), ("1 + 1e-400i", 4), ("1e40i - 1", 0), ("( 1, 1e400 )", 5), (" 1e99 ∠ 0.5", 1), ("1 ∠ 1e400", 6)]
+    {
+        assert_eq!(input.parse::<Complex>(), Err(ParseComplexError::Overflow {position}), "{input}");
+    }
+}
@@ -0,0 +1,74 @@
+use complex::{Complex, Fraction, GenericComplex, GenericFraction};
+
+/// Checks that the `n`th root of `root^n` is `root` for every `root` with components
+/// in the range, whose angle is less than 45 degrees from the positive real axis, so that it
+/// is the principal root for `n` up to 4. 
+fn roots_of_powers<T>(components: &[GenericFraction<T>], n: u32)
+where
+    T: complex::SignedInteger,
+{
+    for &real in components.iter().filter(|real| **real > GenericFraction::ZERO)
+    {
+        for &imaginary in components.iter().filter(|imaginary| imaginary.abs() < real)
+        {
+            let root = GenericComplex::from(real, imaginary);
+
+            if let Ok(power) = root.checked_pow(n as i32)
+            {
+                assert_eq!(power.nth_root(n), Some(root), "{power} {n}");
+            }
+        }
+    }
+}
+
+#[test]
+fn exact_roots()
+{
+    let small: Vec<GenericFraction<i8>> = (-20..=20).flat_map(|numerator| [1, 2, 3, 5, 7].map(|denominator| GenericFraction::from(numerator, denominator).unwrap())).collect();
+    let large: Vec<Fraction> = [-1_000_003, -4096, -3, -1, 0, 1, 2, 17, 65_535, 1_000_003].into_iter().flat_map(|numerator| [1, 3, 4096, 46_337].map(|denominator| Fraction::from(numerator, denominator).unwrap())).collect();
+
+    // components beyond the precision of an `f64`
+    let wide: Vec<GenericFraction<i128>> = [(1 << 60) + 7, -(1 << 59) - 3, 1].into_iter().flat_map(|numerator| [1, 3].map(|denominator| GenericFraction::from(numerator, denominator).unwrap())).collect();
+
+    for n in 2..=4
+    {
+        roots_of_powers(&small, n);
+        roots_of_powers(&large, n);
+        roots_of_powers(&wide, n);
+    }
+
+    // within 20 degrees of the positive real axis, so it is the principal 9th root
+    let root = Complex::from(Fraction::unchecked_from(3, 2), Fraction::unchecked_from(1, 2));
+
+    assert_eq!(root.checked_pow(9).unwrap().nth_root(9), Some(root));
+    assert_eq!(root.checked_pow(9).unwrap().nth_root(3), root.checked_pow(3).ok());
+}
+
+#[test]
+fn irrational_roots()
+{
+    for value in [Complex::I, Complex::from_i32(2), Complex::from_i32(-8), Complex::from_i32_pair(1, 1), Complex::from_i32_pair(i32::MAX, i32::MIN)]
+    {
+        for n in 2..=5
+        {
+            assert_eq!(value.nth_root(n), None, "{value} {n}");
+        }
+    }
+
+    assert_eq!(Complex::ONE.nth_root(u32::MAX), Some(Complex::ONE));
+    assert_eq!(Complex::from_i32(-1).nth_root(1000), None);
+    assert_eq!(GenericComplex::from(GenericFraction::<i128>::ONE, GenericFraction::from_integer(i128::MAX)).nth_root(4 * 128), None);
+}
+
+#[test]
+fn rational_powers()
+{
+    let value = Complex::from_i32_pair(-7, 24);
+
+    assert_eq!(value.pow_frac(Fraction::unchecked_from(1, 2)), Some(Complex::from_i32_pair(3, 4)));
+    assert_eq!(value.pow_frac(Fraction::unchecked_from(3, 2)), Some(Complex::from_i32_pair(-117, 44)));
+    assert_eq!(value.pow_frac(Fraction::unchecked_from(-1, 2)), Some(Complex::from(Fraction::unchecked_from(3, 25), Fraction::unchecked_from(-4, 25))));
+    assert_eq!(value.pow_frac(Fraction::unchecked_from(4, 2)), value.checked_pow(2).ok());
+    assert_eq!(value.pow_frac(Fraction::unchecked_from(1, 0)), None);
+    assert_eq!(Complex::ZERO.pow_frac(Fraction::unchecked_from(-1, 2)), None);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "serde")]
+
+use complex::{Complex, Fraction, GenericComplex, GenericFraction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Defaults
+{
+    ratio: Fraction,
+    position: Complex,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Strings
+{
+    #[serde(with = "complex::serde::string")]
+    ratio: Fraction,
+    #[serde(with = "complex::serde::string")]
+    position: Complex,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tuples
+{
+    #[serde(with = "complex::serde::tuple")]
+    ratio: Fraction,
+    #[serde(with = "complex::serde::tuple")]
+    position: Complex,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Maps
+{
+    #[serde(with = "complex::serde::map")]
+    ratio: Fraction,
+    #[serde(with = "complex::serde::map")]
+    position: Complex,
+}
+
+fn values() -> Vec<(Fraction, Complex)>
+{
+    let fractions =
+    [
+        Fraction::from_i32(0),
+        Fraction::unchecked_from(-3, 4),
+        Fraction::unchecked_from(i32::MIN, u32::MAX),
+        Fraction::unchecked_from(i32::MAX, 1),
+    ];
+
+    fractions.iter()
+        .flat_map(|&ratio| fractions.iter().map(move |&imaginary| (ratio, Complex::from(ratio, imaginary))))
+        .collect()
+}
+
+#[test]
+fn round_trips()
+{
+    for (ratio, position) in values()
+    {
+        let json = serde_json::to_string(&Defaults {ratio, position}).unwrap();
+        assert_eq!(serde_json::from_str::<Defaults>(&json).unwrap(), Defaults {ratio, position}, "{json}");
+
+        let json = serde_json::to_string(&Strings {ratio, position}).unwrap();
+        assert_eq!(serde_json::from_str::<Strings>(&json).unwrap(), Strings {ratio, position}, "{json}");
+
+        let json = serde_json::to_string(&Tuples {ratio, position}).unwrap();
+        assert_eq!(serde_json::from_str::<Tuples>(&json).unwrap(), Tuples {ratio, position}, "{json}");
+
+        let json = serde_json::to_string(&Maps {ratio, position}).unwrap();
+        assert_eq!(serde_json::from_str::<Maps>(&json).unwrap(), Maps {ratio, position}, "{json}");
+    }
+}
+
+#[test]
+fn other_widths()
+{
+    let value = GenericFraction::<i128>::unchecked_from(i128::MIN, u128::MAX);
+
+    let json = serde_json::to_string(&value).unwrap();
+
+    assert_eq!(serde_json::from_str::<GenericFraction<i128>>(&json).unwrap(), value);
+
+    let tuple = format!("[{}, {}]", i128::MIN, u128::MAX);
+
+    assert_eq!(serde_json::from_str::<GenericFraction<i128>>(&tuple).unwrap(), value);
+    assert!(serde_json::from_str::<GenericFraction<i8>>("[128, 1]").is_err());
+    assert!(serde_json::from_str::<GenericFraction<i8>>("200").is_err());
+    assert_eq!(serde_json::from_str::<GenericComplex<GenericFraction<i8>>>("-128").unwrap(), GenericComplex::from_integer_pair(-128, 0));
+}
+
+#[test]
+fn canonical_form()
+{
+    let serialized = serde_json::to_string(&Tuples {ratio: Fraction::unchecked_from(6, 8), position: Complex::from(Fraction::unchecked_from(0, 5), Fraction::unchecked_from(-4, 2))}).unwrap();
+
+    assert_eq!(serialized, r#"{"ratio":[3,4],"position":[[0,1],[-2,1]]}"#);
+
+    let deserialized: Maps = serde_json::from_str(r#"{"ratio": {"den": 8, "num": 6}, "position": {"re": {"num": 0, "den": 5}, "im": {"num": -4, "den": 2}}}"#).unwrap();
+
+    assert_eq!(deserialized.ratio.get_components(), (3, 4));
+    assert!(deserialized.position.is_canonical());
+    assert_eq!(deserialized.position, Complex::from_i32_pair(0, -2));
+}
+
+#[test]
+fn zero_denominators()
+{
+    let invalid = Fraction::unchecked_from(1, 0);
+
+    assert!(serde_json::to_string(&invalid).is_err());
+    assert!(serde_json::to_string(&Complex::from(Fraction::from_i32(1), invalid)).is_err());
+
+    assert!(serde_json::from_str::<Fraction>(r#""1/0""#).is_err());
+    assert!(serde_json::from_str::<Fraction>("[1, 0]").is_err());
+    assert!(serde_json::from_str::<Fraction>(r#"{"num": 1, "den": 0}"#).is_err());
+    assert!(serde_json::from_str::<Complex>("[1, [1, 0]]").is_err());
+    assert!(serde_json::from_str::<Tuples>(r#"{"ratio": [0, 0], "position": [0, 0]}"#).is_err());
+}
+
+#[test]
+fn malformed_input()
+{
+    assert!(serde_json::from_str::<Fraction>("[1]").is_err());
+    assert!(serde_json::from_str::<Fraction>("[1, 2, 3]").is_err());
+    assert!(serde_json::from_str::<Fraction>(r#"{"num": 1}"#).is_err());
+    assert!(serde_json::from_str::<Fraction>(r#"{"num": 1, "den": 2, "extra": 3}"#).is_err());
+    assert!(serde_json::from_str::<Fraction>(r#"{"num": 1, "num": 2, "den": 3}"#).is_err());
+    assert!(serde_json::from_str::<Fraction>(r#""one half""#).is_err());
+    assert!(serde_json::from_str::<Fraction>("0.5").is_err());
+    assert!(serde_json::from_str::<Complex>(r#"{"re": 1}"#).is_err());
+
+    // the selected forms only accept that form
+    assert!(serde_json::from_str::<Strings>(r#"{"ratio": [1, 2], "position": "i"}"#).is_err());
+    assert!(serde_json::from_str::<Tuples>(r#"{"ratio": "1/2", "position": [0, 1]}"#).is_err());
+    assert!(serde_json::from_str::<Maps>(r#"{"ratio": "1/2", "position": {"re": {"num": 0, "den": 1}, "im": {"num": 1, "den": 1}}}"#).is_err());
+}